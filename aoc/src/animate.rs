@@ -0,0 +1,214 @@
+use crate::days;
+use crate::fetch::get_default_input_path;
+use common::input::read_input;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::style::Print;
+use crossterm::terminal::{self, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{cursor, execute, queue};
+use std::io::{self, IsTerminal, Stdout, Write};
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+const MAX_SPEED: u32 = 1000;
+const CONTROLS: &str = "space pause, <-/-> step, +/- speed, r restart, q quit";
+
+pub fn run(
+    day_number: u8,
+    input: Option<&str>,
+    cache: &Path,
+    speed: u32,
+    max_frame_count: usize,
+) -> ExitCode {
+    let Some(day) = days::find(day_number) else {
+        eprintln!("Day {day_number} is not solved.");
+        return ExitCode::FAILURE;
+    };
+    let Some(animate) = day.animate else {
+        eprintln!("Day {day_number} has no animation.");
+        return ExitCode::FAILURE;
+    };
+
+    let raw_input = match read_input(input, get_default_input_path(day, cache)) {
+        Ok(raw_input) => raw_input,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let frames = match animate(&raw_input, max_frame_count) {
+        Ok(frames) => frames,
+        Err(error) => {
+            eprintln!("Invalid input, {error}.");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Piped somewhere, the frames are only written one after the other.
+    if !io::stdout().is_terminal() {
+        println!("{}", frames.join("\n\n"));
+        return ExitCode::SUCCESS;
+    }
+
+    if let Err(error) = play(&frames, speed) {
+        eprintln!("Could not play the animation: {error}");
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+struct Player {
+    frame_count: usize,
+    frame_index: usize,
+    speed: u32,
+    is_paused: bool,
+}
+
+enum Action {
+    Continue,
+    Quit,
+}
+
+impl Player {
+    fn get_frame_duration(&self) -> Duration {
+        Duration::from_secs(1) / self.speed
+    }
+
+    // Goes to the next frame, pausing on the last one.
+    fn step_forward(&mut self) {
+        if self.frame_index + 1 < self.frame_count {
+            self.frame_index += 1;
+        }
+        if self.frame_index + 1 == self.frame_count {
+            self.is_paused = true;
+        }
+    }
+
+    fn handle_key(&mut self, key_event: KeyEvent) -> Action {
+        match key_event.code {
+            KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Action::Quit
+            }
+            KeyCode::Char('q') | KeyCode::Esc => return Action::Quit,
+            KeyCode::Char(' ') => {
+                // Resuming from the last frame replays the animation.
+                if self.is_paused && self.frame_index + 1 == self.frame_count {
+                    self.frame_index = 0;
+                }
+                self.is_paused = !self.is_paused;
+            }
+            KeyCode::Right | KeyCode::Char('n') => {
+                self.step_forward();
+                self.is_paused = true;
+            }
+            KeyCode::Left | KeyCode::Char('p') => {
+                self.frame_index = self.frame_index.saturating_sub(1);
+                self.is_paused = true;
+            }
+            KeyCode::Up | KeyCode::Char('+') => self.speed = (self.speed * 2).min(MAX_SPEED),
+            KeyCode::Down | KeyCode::Char('-') => self.speed = (self.speed / 2).max(1),
+            KeyCode::Home | KeyCode::Char('r') => self.frame_index = 0,
+            _ => {}
+        }
+        Action::Continue
+    }
+
+    fn get_status(&self) -> String {
+        format!(
+            "frame {}/{} | {} fps{} | {CONTROLS}",
+            self.frame_index + 1,
+            self.frame_count,
+            self.speed,
+            if self.is_paused { " | paused" } else { "" },
+        )
+    }
+}
+
+// Restores the terminal however the player stops, even on a panic.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn new(stdout: &mut Stdout) -> io::Result<TerminalGuard> {
+        terminal::enable_raw_mode()?;
+        execute!(stdout, EnterAlternateScreen, cursor::Hide)?;
+        Ok(TerminalGuard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn play(frames: &[String], speed: u32) -> io::Result<()> {
+    if frames.is_empty() {
+        return Ok(());
+    }
+
+    let mut stdout = io::stdout();
+    let _terminal_guard = TerminalGuard::new(&mut stdout)?;
+    let mut player = Player {
+        frame_count: frames.len(),
+        frame_index: 0,
+        speed: speed.clamp(1, MAX_SPEED),
+        is_paused: frames.len() == 1,
+    };
+    let mut next_frame_at = Instant::now() + player.get_frame_duration();
+
+    loop {
+        draw(
+            &mut stdout,
+            &frames[player.frame_index],
+            &player.get_status(),
+        )?;
+
+        // Waiting for a key until the next frame is due, or as long as it
+        // takes when paused.
+        let timeout = if player.is_paused {
+            Duration::from_secs(3600)
+        } else {
+            next_frame_at.saturating_duration_since(Instant::now())
+        };
+        if event::poll(timeout)? {
+            if let Event::Key(key_event) = event::read()? {
+                let was_paused = player.is_paused;
+                if let Action::Quit = player.handle_key(key_event) {
+                    return Ok(());
+                }
+                if was_paused && !player.is_paused {
+                    next_frame_at = Instant::now() + player.get_frame_duration();
+                }
+            }
+        } else if !player.is_paused {
+            player.step_forward();
+            next_frame_at += player.get_frame_duration();
+        }
+    }
+}
+
+// Draws the top left corner of the frame that fits in the terminal, above the
+// status line.
+fn draw(stdout: &mut Stdout, frame: &str, status: &str) -> io::Result<()> {
+    let (column_count, line_count) = terminal::size()?;
+    let frame_line_count = line_count.saturating_sub(1);
+
+    queue!(stdout, terminal::Clear(ClearType::All))?;
+    for (line_index, line) in frame.lines().take(frame_line_count as usize).enumerate() {
+        let visible_line: String = line.chars().take(column_count as usize).collect();
+        queue!(
+            stdout,
+            cursor::MoveTo(0, line_index as u16),
+            Print(visible_line)
+        )?;
+    }
+    let visible_status: String = status.chars().take(column_count as usize).collect();
+    queue!(
+        stdout,
+        cursor::MoveTo(0, frame_line_count),
+        Print(visible_status)
+    )?;
+    stdout.flush()
+}
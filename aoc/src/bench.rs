@@ -0,0 +1,107 @@
+use crate::days::{self, Day};
+use crate::fetch::get_default_input_path;
+use common::input::{read_input, InputError};
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Duration;
+
+#[derive(Serialize)]
+struct PhaseReport {
+    day: u8,
+    phase: &'static str,
+    runs: usize,
+    min_ns: u128,
+    median_ns: u128,
+    max_ns: u128,
+}
+
+pub fn run(day_numbers: &[u8], repeat: usize, report: Option<&Path>, cache: &Path) -> ExitCode {
+    let mut benched_days: Vec<&Day> = Vec::new();
+    let is_benching_every_day = day_numbers.is_empty();
+    for &day_number in day_numbers {
+        let Some(day) = days::find(day_number) else {
+            eprintln!("Day {day_number} is not solved.");
+            return ExitCode::FAILURE;
+        };
+        benched_days.push(day);
+    }
+    if is_benching_every_day {
+        benched_days = days::DAYS.iter().collect();
+    }
+
+    let mut phase_reports: Vec<PhaseReport> = Vec::new();
+
+    for day in benched_days {
+        let raw_input = match read_input(None, get_default_input_path(day, cache)) {
+            Ok(raw_input) => raw_input,
+            // A day without its real input yet is not worth failing the others.
+            Err(error @ InputError::NotFound(_)) if is_benching_every_day => {
+                eprintln!("{error} Skipping day {}.", day.number);
+                continue;
+            }
+            Err(error) => {
+                eprintln!("{error}");
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let mut parse_durations: Vec<Duration> = Vec::new();
+        let mut part_one_durations: Vec<Duration> = Vec::new();
+        let mut part_two_durations: Vec<Duration> = Vec::new();
+
+        for _run in 0..repeat {
+            let timings = match (day.time)(&raw_input) {
+                Ok(timings) => timings,
+                Err(error) => {
+                    eprintln!("{error}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            parse_durations.push(timings.parse);
+            part_one_durations.push(timings.part_one);
+            part_two_durations.push(timings.part_two);
+        }
+
+        for (phase, durations) in [
+            ("parse", parse_durations),
+            ("part one", part_one_durations),
+            ("part two", part_two_durations),
+        ] {
+            let (min, median, max) = get_spread(durations);
+            println!(
+                "Day {:02} {phase:<8}  min {min:>12?}  median {median:>12?}  max {max:>12?}",
+                day.number
+            );
+            phase_reports.push(PhaseReport {
+                day: day.number,
+                phase,
+                runs: repeat,
+                min_ns: min.as_nanos(),
+                median_ns: median.as_nanos(),
+                max_ns: max.as_nanos(),
+            });
+        }
+    }
+
+    if let Some(report) = report {
+        let json = serde_json::to_string_pretty(&phase_reports).unwrap();
+        if let Err(error) = fs::write(report, json + "\n") {
+            eprintln!("Could not write report {}: {error}", report.display());
+            return ExitCode::FAILURE;
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn get_spread(mut durations: Vec<Duration>) -> (Duration, Duration, Duration) {
+    durations.sort();
+
+    (
+        durations[0],
+        durations[durations.len() / 2],
+        durations[durations.len() - 1],
+    )
+}
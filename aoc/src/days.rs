@@ -0,0 +1,352 @@
+use common::fuzz::Crash;
+use common::oracle::Mismatch;
+use common::stream::StreamError;
+use common::{Error, Image, ParseError, Part, Timings};
+use std::io::BufRead;
+
+/// Parses an input and records up to a number of frames of its simulation.
+pub type Animate = fn(&str, usize) -> Result<Vec<String>, ParseError>;
+
+/// Parses an input and draws the pictures of its state.
+pub type Render = fn(&str) -> Result<Vec<Image>, ParseError>;
+
+/// Makes up an input from a seed, of the given size or the real input's one.
+pub type Generate = fn(u64, Option<usize>) -> String;
+
+/// Compares the naive and optimised solutions on a number of inputs generated
+/// from a seed, up to a size.
+pub type Compare = fn(u64, usize, Option<usize>) -> Result<(), Mismatch>;
+
+/// Parses a number of mutated inputs generated from a seed, looking for one
+/// making the parser panic.
+pub type Fuzz = fn(u64, usize) -> Result<(), Crash>;
+
+/// Solves both parts while reading an input, giving the answers as printed.
+pub type Stream = fn(&mut dyn BufRead) -> Result<(String, String), StreamError>;
+
+pub struct Day {
+    pub number: u8,
+    pub default_input: &'static str,
+    pub solve: fn(&str, Part) -> Result<String, Error>,
+    pub time: fn(&str) -> Result<Timings, Error>,
+    /// Records the frames of the day's simulation, for the days having one
+    pub animate: Option<Animate>,
+    /// Draws the day's state as pictures, for the days having a rendering
+    pub render: Option<Render>,
+    /// Makes up inputs of the day, for the days having a generator
+    pub generate: Option<Generate>,
+    /// Compares the solutions with the naive ones, for the days keeping them
+    pub compare: Option<Compare>,
+    /// Fuzzes the parser of the day, for the days having a generator
+    pub fuzz: Option<Fuzz>,
+    /// Solves the day while reading its input, for the days able to do so in
+    /// bounded memory
+    pub stream: Option<Stream>,
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        default_input: day01::DEFAULT_INPUT,
+        solve: common::solve::<day01::Day01>,
+        time: common::time::<day01::Day01>,
+        animate: None,
+        render: None,
+        generate: Some(common::generator::generate::<day01::Day01>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day01::Day01>),
+        stream: Some(common::stream::solve::<day01::Day01>),
+    },
+    Day {
+        number: 2,
+        default_input: day02::DEFAULT_INPUT,
+        solve: common::solve::<day02::Day02>,
+        time: common::time::<day02::Day02>,
+        animate: None,
+        render: None,
+        generate: Some(common::generator::generate::<day02::Day02>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day02::Day02>),
+        stream: None,
+    },
+    Day {
+        number: 3,
+        default_input: day03::DEFAULT_INPUT,
+        solve: common::solve::<day03::Day03>,
+        time: common::time::<day03::Day03>,
+        animate: None,
+        render: None,
+        generate: Some(common::generator::generate::<day03::Day03>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day03::Day03>),
+        stream: None,
+    },
+    Day {
+        number: 4,
+        default_input: day04::DEFAULT_INPUT,
+        solve: common::solve::<day04::Day04>,
+        time: common::time::<day04::Day04>,
+        animate: None,
+        render: None,
+        generate: Some(common::generator::generate::<day04::Day04>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day04::Day04>),
+        stream: None,
+    },
+    Day {
+        number: 5,
+        default_input: day05::DEFAULT_INPUT,
+        solve: common::solve::<day05::Day05>,
+        time: common::time::<day05::Day05>,
+        animate: None,
+        render: None,
+        generate: Some(common::generator::generate::<day05::Day05>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day05::Day05>),
+        stream: None,
+    },
+    Day {
+        number: 6,
+        default_input: day06::DEFAULT_INPUT,
+        solve: common::solve::<day06::Day06>,
+        time: common::time::<day06::Day06>,
+        animate: None,
+        render: None,
+        generate: Some(common::generator::generate::<day06::Day06>),
+        compare: Some(common::oracle::compare::<day06::Day06>),
+        fuzz: Some(common::fuzz::fuzz::<day06::Day06>),
+        stream: Some(common::stream::solve::<day06::Day06>),
+    },
+    Day {
+        number: 7,
+        default_input: day07::DEFAULT_INPUT,
+        solve: common::solve::<day07::Day07>,
+        time: common::time::<day07::Day07>,
+        animate: None,
+        render: None,
+        generate: Some(common::generator::generate::<day07::Day07>),
+        compare: Some(common::oracle::compare::<day07::Day07>),
+        fuzz: Some(common::fuzz::fuzz::<day07::Day07>),
+        stream: None,
+    },
+    Day {
+        number: 8,
+        default_input: day08::DEFAULT_INPUT,
+        solve: common::solve::<day08::Day08>,
+        time: common::time::<day08::Day08>,
+        animate: None,
+        render: Some(common::image::render::<day08::Day08>),
+        generate: Some(common::generator::generate::<day08::Day08>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day08::Day08>),
+        stream: None,
+    },
+    Day {
+        number: 9,
+        default_input: day09::DEFAULT_INPUT,
+        solve: common::solve::<day09::Day09>,
+        time: common::time::<day09::Day09>,
+        animate: Some(common::animation::record::<day09::Day09>),
+        render: None,
+        generate: Some(common::generator::generate::<day09::Day09>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day09::Day09>),
+        stream: None,
+    },
+    Day {
+        number: 10,
+        default_input: day10::DEFAULT_INPUT,
+        solve: common::solve::<day10::Day10>,
+        time: common::time::<day10::Day10>,
+        animate: None,
+        render: Some(common::image::render::<day10::Day10>),
+        generate: Some(common::generator::generate::<day10::Day10>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day10::Day10>),
+        stream: None,
+    },
+    Day {
+        number: 11,
+        default_input: day11::DEFAULT_INPUT,
+        solve: common::solve::<day11::Day11>,
+        time: common::time::<day11::Day11>,
+        animate: None,
+        render: None,
+        generate: Some(common::generator::generate::<day11::Day11>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day11::Day11>),
+        stream: None,
+    },
+    Day {
+        number: 12,
+        default_input: day12::DEFAULT_INPUT,
+        solve: common::solve::<day12::Day12>,
+        time: common::time::<day12::Day12>,
+        animate: None,
+        render: Some(common::image::render::<day12::Day12>),
+        generate: Some(common::generator::generate::<day12::Day12>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day12::Day12>),
+        stream: None,
+    },
+    Day {
+        number: 13,
+        default_input: day13::DEFAULT_INPUT,
+        solve: common::solve::<day13::Day13>,
+        time: common::time::<day13::Day13>,
+        animate: None,
+        render: None,
+        generate: Some(common::generator::generate::<day13::Day13>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day13::Day13>),
+        stream: Some(common::stream::solve::<day13::Day13>),
+    },
+    Day {
+        number: 14,
+        default_input: day14::DEFAULT_INPUT,
+        solve: common::solve::<day14::Day14>,
+        time: common::time::<day14::Day14>,
+        animate: Some(common::animation::record::<day14::Day14>),
+        render: Some(common::image::render::<day14::Day14>),
+        generate: Some(common::generator::generate::<day14::Day14>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day14::Day14>),
+        stream: None,
+    },
+    Day {
+        number: 15,
+        default_input: day15::DEFAULT_INPUT,
+        solve: common::solve::<day15::Day15>,
+        time: common::time::<day15::Day15>,
+        animate: None,
+        render: Some(common::image::render::<day15::Day15>),
+        generate: Some(common::generator::generate::<day15::Day15>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day15::Day15>),
+        stream: None,
+    },
+    Day {
+        number: 16,
+        default_input: day16::DEFAULT_INPUT,
+        solve: common::solve::<day16::Day16>,
+        time: common::time::<day16::Day16>,
+        animate: None,
+        render: None,
+        generate: Some(common::generator::generate::<day16::Day16>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day16::Day16>),
+        stream: None,
+    },
+    Day {
+        number: 17,
+        default_input: day17::DEFAULT_INPUT,
+        solve: common::solve::<day17::Day17>,
+        time: common::time::<day17::Day17>,
+        animate: Some(common::animation::record::<day17::Day17>),
+        render: Some(common::image::render::<day17::Day17>),
+        generate: Some(common::generator::generate::<day17::Day17>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day17::Day17>),
+        stream: Some(common::stream::solve::<day17::Day17>),
+    },
+    Day {
+        number: 18,
+        default_input: day18::DEFAULT_INPUT,
+        solve: common::solve::<day18::Day18>,
+        time: common::time::<day18::Day18>,
+        animate: None,
+        render: None,
+        generate: Some(common::generator::generate::<day18::Day18>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day18::Day18>),
+        stream: None,
+    },
+    Day {
+        number: 19,
+        default_input: day19::DEFAULT_INPUT,
+        solve: common::solve::<day19::Day19>,
+        time: common::time::<day19::Day19>,
+        animate: None,
+        render: None,
+        generate: Some(common::generator::generate::<day19::Day19>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day19::Day19>),
+        stream: None,
+    },
+    Day {
+        number: 20,
+        default_input: day20::DEFAULT_INPUT,
+        solve: common::solve::<day20::Day20>,
+        time: common::time::<day20::Day20>,
+        animate: None,
+        render: None,
+        generate: Some(common::generator::generate::<day20::Day20>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day20::Day20>),
+        stream: None,
+    },
+    Day {
+        number: 21,
+        default_input: day21::DEFAULT_INPUT,
+        solve: common::solve::<day21::Day21>,
+        time: common::time::<day21::Day21>,
+        animate: None,
+        render: None,
+        generate: Some(common::generator::generate::<day21::Day21>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day21::Day21>),
+        stream: None,
+    },
+    Day {
+        number: 22,
+        default_input: day22::DEFAULT_INPUT,
+        solve: common::solve::<day22::Day22>,
+        time: common::time::<day22::Day22>,
+        animate: None,
+        render: None,
+        generate: Some(common::generator::generate::<day22::Day22>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day22::Day22>),
+        stream: None,
+    },
+    Day {
+        number: 23,
+        default_input: day23::DEFAULT_INPUT,
+        solve: common::solve::<day23::Day23>,
+        time: common::time::<day23::Day23>,
+        animate: Some(common::animation::record::<day23::Day23>),
+        render: Some(common::image::render::<day23::Day23>),
+        generate: Some(common::generator::generate::<day23::Day23>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day23::Day23>),
+        stream: None,
+    },
+    Day {
+        number: 24,
+        default_input: day24::DEFAULT_INPUT,
+        solve: common::solve::<day24::Day24>,
+        time: common::time::<day24::Day24>,
+        animate: Some(common::animation::record::<day24::Day24>),
+        render: None,
+        generate: Some(common::generator::generate::<day24::Day24>),
+        compare: Some(common::oracle::compare::<day24::Day24>),
+        fuzz: Some(common::fuzz::fuzz::<day24::Day24>),
+        stream: None,
+    },
+    Day {
+        number: 25,
+        default_input: day25::DEFAULT_INPUT,
+        solve: common::solve::<day25::Day25>,
+        time: common::time::<day25::Day25>,
+        animate: None,
+        render: None,
+        generate: Some(common::generator::generate::<day25::Day25>),
+        compare: None,
+        fuzz: Some(common::fuzz::fuzz::<day25::Day25>),
+        stream: None,
+    },
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,52 @@
+use crate::days::Day;
+use crate::website::{Website, YEAR};
+use common::input::get_default_path;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// Where the input of a day is cached, whatever the account it was fetched
+/// with.
+pub fn get_cached_input_path(cache: &Path, day: u8) -> PathBuf {
+    cache.join(YEAR.to_string()).join(format!("{day:02}.txt"))
+}
+
+/// The input a day reads unless given another one: its real.txt, or its cached
+/// download until real.txt is added.
+pub fn get_default_input_path(day: &Day, cache: &Path) -> PathBuf {
+    let cached_input_path = get_cached_input_path(cache, day.number);
+    get_default_path(Path::new(day.default_input), &cached_input_path).to_path_buf()
+}
+
+pub fn run(day: u8, cache: &Path, url: &str, session: Option<&str>) -> ExitCode {
+    let input_path = get_cached_input_path(cache, day);
+    if input_path.exists() {
+        println!("{}", input_path.display());
+        return ExitCode::SUCCESS;
+    }
+
+    let Some(session) = session else {
+        eprintln!("No session token to download the input with, set AOC_SESSION or --session.");
+        return ExitCode::FAILURE;
+    };
+
+    let input = match Website::new(url, session).get_input(day) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // The input is only written once fully downloaded, so that a failed
+    // download is retried next time.
+    let written = fs::create_dir_all(input_path.parent().unwrap())
+        .and_then(|()| fs::write(&input_path, input));
+    if let Err(error) = written {
+        eprintln!("Could not write {}: {error}", input_path.display());
+        return ExitCode::FAILURE;
+    }
+
+    println!("{}", input_path.display());
+    ExitCode::SUCCESS
+}
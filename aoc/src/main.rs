@@ -0,0 +1,424 @@
+use crate::fetch::get_default_input_path;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use common::input::{open_input, read_input};
+use common::Part;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+mod animate;
+mod bench;
+mod days;
+mod fetch;
+mod scaffold;
+mod submit;
+mod website;
+
+const RENDER_FRAME_DELAY: Duration = Duration::from_millis(50);
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day of the calendar
+    Run {
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, `-` for the standard input, the day's real.txt or cached input by default
+        input: Option<String>,
+        /// Directory of the downloaded inputs, the day's cached input being read
+        /// when it has no real.txt yet
+        #[arg(long, env = "AOC_CACHE", default_value = ".aoc-cache")]
+        cache: PathBuf,
+        /// Print the bare answers, or one JSON object per part
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Also draw the day's state to a .png, .ppm or animated .gif file
+        #[arg(long)]
+        render: Option<PathBuf>,
+        /// Solve both parts while reading the input, in bounded memory, for the days able to
+        #[arg(long, conflicts_with = "render")]
+        stream: bool,
+    },
+    /// Replay the simulation of a day in the terminal
+    Animate {
+        day: u8,
+        /// Input file, `-` for the standard input, the day's real.txt or cached input by default
+        input: Option<String>,
+        /// Directory of the downloaded inputs, the day's cached input being read
+        /// when it has no real.txt yet
+        #[arg(long, env = "AOC_CACHE", default_value = ".aoc-cache")]
+        cache: PathBuf,
+        /// Frames shown per second
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..=1000))]
+        speed: u32,
+        /// Number of frames recorded at most, long simulations being cut
+        #[arg(long, default_value_t = 5000, value_parser = clap::value_parser!(u32).range(1..))]
+        max_frames: u32,
+    },
+    /// Make up a random input of a day, printed to the standard output
+    Generate {
+        day: u8,
+        /// Scale of the input, in a unit depending on the day, the real input's by default
+        #[arg(long)]
+        size: Option<usize>,
+        /// Seed of the random numbers, the same seed giving the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Compare the optimised solutions of a day with its naive ones on generated inputs
+    Compare {
+        day: u8,
+        /// Number of inputs to compare the solutions on
+        #[arg(long, default_value_t = 1000, value_parser = clap::value_parser!(u32).range(1..))]
+        inputs: u32,
+        /// Seed of the first input, the next ones following it
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Size the inputs grow to, the real input's or a smaller one by default
+        #[arg(long)]
+        size: Option<usize>,
+    },
+    /// Parse mutated inputs of a day, looking for one making the parser panic
+    Fuzz {
+        day: u8,
+        /// Number of inputs to parse
+        #[arg(long, default_value_t = 10000, value_parser = clap::value_parser!(u32).range(1..))]
+        inputs: u32,
+        /// Seed of the first input, the next ones following it
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Time the parsing and both parts of days on their real input
+    Bench {
+        /// Days to benchmark, every solved day by default
+        days: Vec<u8>,
+        /// Number of runs of each day
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        repeat: u32,
+        /// Write the min, median and max durations of every phase as JSON to this file
+        #[arg(long)]
+        report: Option<PathBuf>,
+        /// Directory of the downloaded inputs, the day's cached input being read
+        /// when it has no real.txt yet
+        #[arg(long, env = "AOC_CACHE", default_value = ".aoc-cache")]
+        cache: PathBuf,
+    },
+    /// Download the input of a day, unless it is already cached
+    Fetch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[command(flatten)]
+        website: WebsiteArgs,
+    },
+    /// Solve a part of a day and submit its answer, unless it is known to be wrong
+    Submit {
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Input file, `-` for the standard input, the day's real.txt or cached input by default
+        input: Option<String>,
+        #[command(flatten)]
+        website: WebsiteArgs,
+    },
+    /// Create the crate of a new day and register it in the runner
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Root of the workspace
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+        /// Directory of the downloaded inputs, the day's real.txt being copied
+        /// from it when fetched already
+        #[arg(long, env = "AOC_CACHE", default_value = ".aoc-cache")]
+        cache: PathBuf,
+    },
+}
+
+#[derive(Args)]
+struct WebsiteArgs {
+    /// Directory of the downloaded inputs, stored as <year>/<day>.txt, and of
+    /// the ledger of the submitted answers, <year>/answers.json
+    #[arg(long, env = "AOC_CACHE", default_value = ".aoc-cache")]
+    cache: PathBuf,
+    /// Value of the `session` cookie of a logged in account
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+    /// Address of the website
+    #[arg(long, env = "AOC_URL", default_value = website::DEFAULT_URL)]
+    url: String,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Serialize)]
+struct AnswerReport<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    /// Seconds spent parsing the input and solving the part
+    duration: f64,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            cache,
+            format,
+            render,
+            stream,
+        } => match stream {
+            true => run_streaming(day, part, input.as_deref(), &cache, format),
+            false => run(
+                day,
+                part,
+                input.as_deref(),
+                &cache,
+                format,
+                render.as_deref(),
+            ),
+        },
+        Command::Animate {
+            day,
+            input,
+            cache,
+            speed,
+            max_frames,
+        } => animate::run(day, input.as_deref(), &cache, speed, max_frames as usize),
+        Command::Generate { day, size, seed } => generate(day, size, seed),
+        Command::Compare {
+            day,
+            inputs,
+            seed,
+            size,
+        } => compare(day, inputs as usize, seed, size),
+        Command::Fuzz { day, inputs, seed } => fuzz(day, inputs as usize, seed),
+        Command::Bench {
+            days,
+            repeat,
+            report,
+            cache,
+        } => bench::run(&days, repeat as usize, report.as_deref(), &cache),
+        Command::Fetch { day, website } => fetch::run(
+            day,
+            &website.cache,
+            &website.url,
+            website.session.as_deref(),
+        ),
+        Command::Submit {
+            day,
+            part,
+            input,
+            website,
+        } => submit::run(
+            day,
+            part,
+            input.as_deref(),
+            &website.cache,
+            &website.url,
+            website.session.as_deref(),
+        ),
+        Command::New { day, root, cache } => scaffold::run(day, &root, &cache),
+    }
+}
+
+fn run(
+    day_number: u8,
+    part: Option<u8>,
+    input: Option<&str>,
+    cache: &Path,
+    format: Format,
+    render_path: Option<&Path>,
+) -> ExitCode {
+    let Some(day) = days::find(day_number) else {
+        eprintln!("Day {day_number} is not solved.");
+        return ExitCode::FAILURE;
+    };
+    let render = match (render_path, day.render) {
+        (Some(_), None) => {
+            eprintln!("Day {day_number} has no rendering.");
+            return ExitCode::FAILURE;
+        }
+        (Some(render_path), Some(render)) => Some((render_path, render)),
+        (None, _) => None,
+    };
+
+    let raw_input = match read_input(input, get_default_input_path(day, cache)) {
+        Ok(raw_input) => raw_input,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for part in get_parts(part) {
+        let start = Instant::now();
+        let answer = match (day.solve)(&raw_input, part) {
+            Ok(answer) => answer,
+            Err(error) => {
+                eprintln!("{error}");
+                return ExitCode::FAILURE;
+            }
+        };
+        print_answer(day.number, part, &answer, start.elapsed(), format);
+    }
+
+    if let Some((render_path, render)) = render {
+        let frames = match render(&raw_input) {
+            Ok(frames) => frames,
+            Err(error) => {
+                eprintln!("Invalid input, {error}.");
+                return ExitCode::FAILURE;
+            }
+        };
+        if let Err(error) = common::image::save(render_path, &frames, RENDER_FRAME_DELAY) {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+// Both parts are solved in the same pass, so each one reports the duration of
+// the whole pass.
+fn run_streaming(
+    day_number: u8,
+    part: Option<u8>,
+    input: Option<&str>,
+    cache: &Path,
+    format: Format,
+) -> ExitCode {
+    let Some(day) = days::find(day_number) else {
+        eprintln!("Day {day_number} is not solved.");
+        return ExitCode::FAILURE;
+    };
+    let Some(stream) = day.stream else {
+        eprintln!("Day {day_number} cannot stream its input.");
+        return ExitCode::FAILURE;
+    };
+
+    let mut reader = match open_input(input, get_default_input_path(day, cache)) {
+        Ok(reader) => reader,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let start = Instant::now();
+    let (answer_one, answer_two) = match stream(&mut reader) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let duration = start.elapsed();
+
+    for part in get_parts(part) {
+        let answer = match part {
+            Part::One => &answer_one,
+            Part::Two => &answer_two,
+        };
+        print_answer(day.number, part, answer, duration, format);
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn get_parts(part: Option<u8>) -> Vec<Part> {
+    match part.and_then(Part::from_number) {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    }
+}
+
+fn print_answer(day_number: u8, part: Part, answer: &str, duration: Duration, format: Format) {
+    match format {
+        Format::Text => println!("{answer}"),
+        Format::Json => {
+            let answer_report = AnswerReport {
+                day: day_number,
+                part: part.number(),
+                answer,
+                duration: duration.as_secs_f64(),
+            };
+            println!("{}", serde_json::to_string(&answer_report).unwrap());
+        }
+    }
+}
+
+fn generate(day_number: u8, size: Option<usize>, seed: u64) -> ExitCode {
+    let Some(day) = days::find(day_number) else {
+        eprintln!("Day {day_number} is not solved.");
+        return ExitCode::FAILURE;
+    };
+    let Some(generate) = day.generate else {
+        eprintln!("Day {day_number} has no generator.");
+        return ExitCode::FAILURE;
+    };
+
+    print!("{}", generate(seed, size));
+    ExitCode::SUCCESS
+}
+
+fn compare(day_number: u8, input_count: usize, seed: u64, size: Option<usize>) -> ExitCode {
+    let Some(day) = days::find(day_number) else {
+        eprintln!("Day {day_number} is not solved.");
+        return ExitCode::FAILURE;
+    };
+    let Some(compare) = day.compare else {
+        eprintln!("Day {day_number} keeps no naive solution.");
+        return ExitCode::FAILURE;
+    };
+
+    match compare(seed, input_count, size) {
+        Ok(()) => {
+            println!("The naive and optimised solutions agree on {input_count} inputs.");
+            ExitCode::SUCCESS
+        }
+        Err(mismatch) => {
+            eprintln!("{mismatch}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn fuzz(day_number: u8, input_count: usize, seed: u64) -> ExitCode {
+    let Some(day) = days::find(day_number) else {
+        eprintln!("Day {day_number} is not solved.");
+        return ExitCode::FAILURE;
+    };
+    let Some(fuzz) = day.fuzz else {
+        eprintln!("Day {day_number} has no generator to fuzz from.");
+        return ExitCode::FAILURE;
+    };
+
+    match fuzz(seed, input_count) {
+        Ok(()) => {
+            println!("The parser handles {input_count} mutated inputs.");
+            ExitCode::SUCCESS
+        }
+        Err(crash) => {
+            eprintln!("{crash}");
+            ExitCode::FAILURE
+        }
+    }
+}
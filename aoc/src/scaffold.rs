@@ -0,0 +1,177 @@
+use crate::fetch::get_cached_input_path;
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+const CARGO_TOML_TEMPLATE: &str = include_str!("../templates/Cargo.toml.template");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.template");
+const EXAMPLES_TEMPLATE: &str = include_str!("../templates/examples.rs.template");
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs.template");
+const FUZZ_TARGET_TEMPLATE: &str = include_str!("../templates/fuzz_target.rs.template");
+const FUZZ_BIN_TEMPLATE: &str = include_str!("../templates/fuzz_bin.toml.template");
+
+/// Creates the crate of a day in the workspace at `root`, and registers it in
+/// the workspace, in the runner and among the fuzz targets.
+pub fn run(day: u8, root: &Path, cache: &Path) -> ExitCode {
+    match create_day(day, root, cache) {
+        Ok(()) => {
+            println!("{}", root.join(format!("day{day:02}")).display());
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn create_day(day: u8, root: &Path, cache: &Path) -> Result<(), String> {
+    let crate_name = format!("day{day:02}");
+    let crate_path = root.join(&crate_name);
+    if crate_path.exists() {
+        return Err(format!("{} already exists.", crate_path.display()));
+    }
+
+    // Every file is edited in memory first, so that nothing is written unless
+    // the whole workspace can be updated.
+    let workspace_manifest_path = root.join("Cargo.toml");
+    let workspace_manifest = read(&workspace_manifest_path)?;
+    let workspace_manifest = insert_sorted(
+        &workspace_manifest,
+        &format!("    \"{crate_name}\",\n"),
+        day,
+        0,
+        |line| {
+            line.trim()
+                .strip_prefix("\"day")?
+                .strip_suffix("\",")?
+                .parse()
+                .ok()
+        },
+    )
+    .map_err(|error| format!("{}: {error}", workspace_manifest_path.display()))?;
+
+    let dependency = format!("{crate_name} = {{ path = \"../{crate_name}\" }}\n");
+    let parse_dependency_day =
+        |line: &str| line.strip_prefix("day")?.split_once(' ')?.0.parse().ok();
+
+    let runner_manifest_path = root.join("aoc").join("Cargo.toml");
+    let runner_manifest = read(&runner_manifest_path)?;
+    let runner_manifest =
+        insert_sorted(&runner_manifest, &dependency, day, 0, parse_dependency_day)
+            .map_err(|error| format!("{}: {error}", runner_manifest_path.display()))?;
+
+    // Each target is a `[[bin]]` table, after an empty line and before its name.
+    let fuzz_manifest_path = root.join("fuzz").join("Cargo.toml");
+    let fuzz_manifest = read(&fuzz_manifest_path)?;
+    let fuzz_manifest = insert_sorted(&fuzz_manifest, &dependency, day, 0, parse_dependency_day)
+        .and_then(|fuzz_manifest| {
+            insert_sorted(
+                &fuzz_manifest,
+                &fill_template(FUZZ_BIN_TEMPLATE, day),
+                day,
+                2,
+                |line| {
+                    line.trim()
+                        .strip_prefix("name = \"day")?
+                        .strip_suffix('"')?
+                        .parse()
+                        .ok()
+                },
+            )
+        })
+        .map_err(|error| format!("{}: {error}", fuzz_manifest_path.display()))?;
+
+    let days_path = root.join("aoc").join("src").join("days.rs");
+    let days = read(&days_path)?;
+    let days = insert_sorted(&days, &fill_template(DAY_TEMPLATE, day), day, 1, |line| {
+        line.trim()
+            .strip_prefix("number: ")?
+            .strip_suffix(',')?
+            .parse()
+            .ok()
+    })
+    .map_err(|error| format!("{}: {error}", days_path.display()))?;
+
+    let real_input = fs::read_to_string(get_cached_input_path(cache, day)).ok();
+
+    write(
+        &crate_path.join("Cargo.toml"),
+        &fill_template(CARGO_TOML_TEMPLATE, day),
+    )?;
+    write(
+        &crate_path.join("src").join("lib.rs"),
+        &fill_template(LIB_TEMPLATE, day),
+    )?;
+    write(
+        &crate_path.join("tests").join("examples.rs"),
+        &fill_template(EXAMPLES_TEMPLATE, day),
+    )?;
+    write(&crate_path.join("example.txt"), "")?;
+    if let Some(real_input) = real_input {
+        write(&crate_path.join("real.txt"), &real_input)?;
+    }
+    write(
+        &root
+            .join("fuzz")
+            .join("fuzz_targets")
+            .join(format!("{crate_name}.rs")),
+        &fill_template(FUZZ_TARGET_TEMPLATE, day),
+    )?;
+    write(&workspace_manifest_path, &workspace_manifest)?;
+    write(&runner_manifest_path, &runner_manifest)?;
+    write(&fuzz_manifest_path, &fuzz_manifest)?;
+    write(&days_path, &days)
+}
+
+fn fill_template(template: &str, day: u8) -> String {
+    template
+        .replace("__NUMBER__", &day.to_string())
+        .replace("__DAY__", &format!("{day:02}"))
+}
+
+// Inserts an entry among the entries of the other days, keeping them in the
+// order of the days. `parse_day` finds the day of an entry on one of its
+// lines, `marker_offset` lines after its first one.
+fn insert_sorted(
+    text: &str,
+    entry: &str,
+    day: u8,
+    marker_offset: usize,
+    parse_day: impl Fn(&str) -> Option<u8>,
+) -> Result<String, String> {
+    let lines: Vec<&str> = text.split_inclusive('\n').collect();
+    let markers: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(line_index, line)| Some((line_index, parse_day(line)?)))
+        .collect();
+
+    if markers.iter().any(|&(_, marker_day)| marker_day == day) {
+        return Err(format!("day {day} is already registered"));
+    }
+    let insert_index = match markers.iter().find(|&&(_, marker_day)| marker_day > day) {
+        Some(&(line_index, _)) => line_index - marker_offset,
+        None => {
+            let &(line_index, _) = markers
+                .last()
+                .ok_or_else(|| String::from("no day is registered to add this one next to"))?;
+            line_index - marker_offset + entry.lines().count()
+        }
+    };
+
+    let mut new_text: String = lines[..insert_index].concat();
+    new_text.push_str(entry);
+    new_text.push_str(&lines[insert_index..].concat());
+    Ok(new_text)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|error| format!("Could not read {}: {error}", path.display()))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    fs::create_dir_all(path.parent().unwrap())
+        .and_then(|()| fs::write(path, content))
+        .map_err(|error| format!("Could not write {}: {error}", path.display()))
+}
@@ -0,0 +1,216 @@
+use crate::days;
+use crate::fetch::get_default_input_path;
+use crate::website::{Website, YEAR};
+use common::input::read_input;
+use common::Part;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// What the website said of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The answer was not checked, another one having been submitted too
+    /// recently.
+    TooRecent,
+    /// The answer was not checked, the part being already solved.
+    AlreadySolved,
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            Verdict::Correct => "the right answer",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "not the right answer",
+            Verdict::TooRecent => "not checked, an answer was given too recently",
+            Verdict::AlreadySolved => "not checked, the part is already solved",
+            Verdict::Unknown => "not understood from the website response",
+        };
+        write!(f, "{description}")
+    }
+}
+
+/// An answer submitted to the website, as recorded in the ledger.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Attempt {
+    day: u8,
+    part: u8,
+    answer: String,
+    verdict: Verdict,
+    /// Seconds since the Unix epoch
+    submitted_at: u64,
+}
+
+fn get_ledger_path(cache: &Path) -> PathBuf {
+    cache.join(YEAR.to_string()).join("answers.json")
+}
+
+pub fn run(
+    day_number: u8,
+    part_number: u8,
+    input: Option<&str>,
+    cache: &Path,
+    url: &str,
+    session: Option<&str>,
+) -> ExitCode {
+    let Some(day) = days::find(day_number) else {
+        eprintln!("Day {day_number} is not solved.");
+        return ExitCode::FAILURE;
+    };
+    let part = Part::from_number(part_number).unwrap();
+
+    let raw_input = match read_input(input, get_default_input_path(day, cache)) {
+        Ok(raw_input) => raw_input,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let answer = match (day.solve)(&raw_input, part) {
+        Ok(answer) => answer,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let ledger_path = get_ledger_path(cache);
+    let mut attempts = match read_ledger(&ledger_path) {
+        Ok(attempts) => attempts,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(reason) = get_refusal_reason(&attempts, day_number, part_number, &answer) {
+        eprintln!("Not submitting {answer}: {reason}.");
+        return ExitCode::FAILURE;
+    }
+
+    let Some(session) = session else {
+        eprintln!("No session token to submit the answer with, set AOC_SESSION or --session.");
+        return ExitCode::FAILURE;
+    };
+
+    let response = match Website::new(url, session).post_answer(day_number, part_number, &answer) {
+        Ok(response) => response,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let verdict = parse_verdict(&response);
+
+    attempts.push(Attempt {
+        day: day_number,
+        part: part_number,
+        answer: answer.clone(),
+        verdict,
+        submitted_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs()),
+    });
+    if let Err(error) = write_ledger(&ledger_path, &attempts) {
+        eprintln!("{error}");
+        return ExitCode::FAILURE;
+    }
+
+    println!("{answer} is {verdict}.");
+    match verdict {
+        Verdict::Correct => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
+
+fn read_ledger(ledger_path: &Path) -> Result<Vec<Attempt>, String> {
+    match fs::read_to_string(ledger_path) {
+        Ok(ledger) => serde_json::from_str(&ledger)
+            .map_err(|error| format!("Invalid ledger {}: {error}", ledger_path.display())),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(Vec::new()),
+        Err(error) => Err(format!(
+            "Could not read ledger {}: {error}",
+            ledger_path.display()
+        )),
+    }
+}
+
+fn write_ledger(ledger_path: &Path, attempts: &[Attempt]) -> Result<(), String> {
+    fs::create_dir_all(ledger_path.parent().unwrap())
+        .and_then(|()| fs::write(ledger_path, serde_json::to_string_pretty(attempts).unwrap()))
+        .map_err(|error| format!("Could not write ledger {}: {error}", ledger_path.display()))
+}
+
+// An answer is not worth submitting when the website already judged it, or
+// when it is beyond an answer known to be too high or too low.
+fn get_refusal_reason(attempts: &[Attempt], day: u8, part: u8, answer: &str) -> Option<String> {
+    let number = answer.parse::<i128>().ok();
+
+    for attempt in attempts
+        .iter()
+        .filter(|attempt| attempt.day == day && attempt.part == part)
+    {
+        let comparison = number
+            .zip(attempt.answer.parse::<i128>().ok())
+            .map(|(number, attempt_number)| number.cmp(&attempt_number));
+        match attempt.verdict {
+            Verdict::Correct if attempt.answer == answer => {
+                return Some(String::from("it was already accepted"));
+            }
+            Verdict::Correct => {
+                return Some(format!(
+                    "the part was already solved with {}",
+                    attempt.answer
+                ));
+            }
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong if attempt.answer == answer => {
+                return Some(format!(
+                    "it was already submitted and is {}",
+                    attempt.verdict
+                ));
+            }
+            Verdict::TooHigh if comparison.is_some_and(Ordering::is_ge) => {
+                return Some(format!("{} was already found too high", attempt.answer));
+            }
+            Verdict::TooLow if comparison.is_some_and(Ordering::is_le) => {
+                return Some(format!("{} was already found too low", attempt.answer));
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn parse_verdict(response: &str) -> Verdict {
+    if response.contains("That's the right answer") {
+        Verdict::Correct
+    } else if response.contains("That's not the right answer") {
+        if response.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if response.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if response.contains("You gave an answer too recently") {
+        Verdict::TooRecent
+    } else if response.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unknown
+    }
+}
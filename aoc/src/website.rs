@@ -0,0 +1,82 @@
+use std::fmt;
+use std::io::Read;
+
+pub const YEAR: u16 = 2022;
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/capsuleman/advent-of-code-2022";
+
+/// The Advent of Code website, or anything answering like it, logged in with
+/// the `session` cookie of an account.
+pub struct Website {
+    url: String,
+    session: String,
+}
+
+#[derive(Debug)]
+pub enum WebsiteError {
+    /// The server answered with an error status, such as 400 for an expired
+    /// session or 404 for a puzzle not unlocked yet.
+    Status(u16, String),
+    Transport(String),
+}
+
+impl fmt::Display for WebsiteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WebsiteError::Status(status, url) => write!(f, "{url} answered with status {status}."),
+            WebsiteError::Transport(error) => write!(f, "Could not reach the website: {error}."),
+        }
+    }
+}
+
+impl std::error::Error for WebsiteError {}
+
+impl Website {
+    pub fn new(url: &str, session: &str) -> Website {
+        Website {
+            url: String::from(url.trim_end_matches('/')),
+            session: String::from(session),
+        }
+    }
+
+    pub fn get_input(&self, day: u8) -> Result<String, WebsiteError> {
+        let url = format!("{}/{YEAR}/day/{day}/input", self.url);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|error| to_website_error(error, &url))?;
+
+        read_body(response)
+    }
+
+    /// Posts the answer of a part, and returns the page telling whether it is
+    /// right.
+    pub fn post_answer(&self, day: u8, part: u8, answer: &str) -> Result<String, WebsiteError> {
+        let url = format!("{}/{YEAR}/day/{day}/answer", self.url);
+        let response = ureq::post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|error| to_website_error(error, &url))?;
+
+        read_body(response)
+    }
+}
+
+fn to_website_error(error: ureq::Error, url: &str) -> WebsiteError {
+    match error {
+        ureq::Error::Status(status, _) => WebsiteError::Status(status, String::from(url)),
+        ureq::Error::Transport(transport) => WebsiteError::Transport(transport.to_string()),
+    }
+}
+
+fn read_body(response: ureq::Response) -> Result<String, WebsiteError> {
+    let mut body = String::new();
+    response
+        .into_reader()
+        .read_to_string(&mut body)
+        .map_err(|error| WebsiteError::Transport(error.to_string()))?;
+    Ok(body)
+}
@@ -0,0 +1,28 @@
+mod mock_website;
+
+use mock_website::run_aoc;
+
+const DAY_24_EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../day24/example.txt");
+
+#[test]
+fn animate_prints_the_frames_when_not_in_a_terminal() {
+    let output = run_aoc(&["animate", "24", DAY_24_EXAMPLE, "--max-frames", "2"]);
+
+    assert!(output.status.success());
+    let frames = String::from_utf8(output.stdout).unwrap();
+    let frames: Vec<&str> = frames.trim_end().split("\n\n").collect();
+    assert_eq!(frames.len(), 2);
+    assert!(frames[0].starts_with("#E######\n#>>.<^<#\n"));
+    assert!(frames[1].starts_with("#E######\n#.>3.<.#\n"));
+}
+
+#[test]
+fn animate_refuses_days_without_a_simulation() {
+    let output = run_aoc(&["animate", "1", DAY_24_EXAMPLE]);
+
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "Day 1 has no animation.\n"
+    );
+}
@@ -0,0 +1,25 @@
+mod mock_website;
+
+use mock_website::run_aoc;
+
+#[test]
+fn compare_reports_agreeing_solutions() {
+    let output = run_aoc(&["compare", "6", "--inputs", "50", "--seed", "3"]);
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "The naive and optimised solutions agree on 50 inputs.\n"
+    );
+}
+
+#[test]
+fn compare_refuses_days_without_naive_solutions() {
+    let output = run_aoc(&["compare", "1"]);
+
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "Day 1 keeps no naive solution.\n"
+    );
+}
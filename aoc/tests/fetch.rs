@@ -0,0 +1,102 @@
+mod mock_website;
+
+use mock_website::{create_test_directory, run_aoc, MockWebsite};
+use std::fs;
+
+const INPUT: &str = "1000\n2000\n\n3000\n";
+
+#[test]
+fn fetch_downloads_the_input_into_the_cache() {
+    let website = MockWebsite::start(|_| (200, String::from(INPUT)));
+    let cache = create_test_directory("fetch-download");
+
+    let output = run_aoc(&[
+        "fetch",
+        "1",
+        "--cache",
+        cache.to_str().unwrap(),
+        "--session",
+        "secret",
+        "--url",
+        &website.url,
+    ]);
+
+    assert!(output.status.success());
+    let input_path = cache.join("2022").join("01.txt");
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap().trim_end(),
+        input_path.to_str().unwrap()
+    );
+    assert_eq!(fs::read_to_string(input_path).unwrap(), INPUT);
+
+    let requests = website.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2022/day/1/input");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=secret"));
+}
+
+#[test]
+fn fetch_never_downloads_a_cached_input_again() {
+    let website = MockWebsite::start(|_| (200, String::from("downloaded again")));
+    let cache = create_test_directory("fetch-cached");
+    fs::create_dir_all(cache.join("2022")).unwrap();
+    fs::write(cache.join("2022").join("12.txt"), INPUT).unwrap();
+
+    let output = run_aoc(&[
+        "fetch",
+        "12",
+        "--cache",
+        cache.to_str().unwrap(),
+        "--url",
+        &website.url,
+    ]);
+
+    assert!(output.status.success());
+    assert_eq!(
+        fs::read_to_string(cache.join("2022").join("12.txt")).unwrap(),
+        INPUT
+    );
+    assert!(website.requests().is_empty());
+}
+
+#[test]
+fn fetch_caches_nothing_when_the_download_fails() {
+    let website = MockWebsite::start(|_| (400, String::from("Please log in.")));
+    let cache = create_test_directory("fetch-failure");
+
+    let output = run_aoc(&[
+        "fetch",
+        "3",
+        "--cache",
+        cache.to_str().unwrap(),
+        "--session",
+        "expired",
+        "--url",
+        &website.url,
+    ]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("status 400"));
+    assert!(!cache.join("2022").join("03.txt").exists());
+}
+
+#[test]
+fn fetch_requires_a_session_to_download() {
+    let website = MockWebsite::start(|_| (200, String::from(INPUT)));
+    let cache = create_test_directory("fetch-session");
+
+    let output = run_aoc(&[
+        "fetch",
+        "5",
+        "--cache",
+        cache.to_str().unwrap(),
+        "--url",
+        &website.url,
+    ]);
+
+    assert!(!output.status.success());
+    assert!(website.requests().is_empty());
+}
@@ -0,0 +1,20 @@
+mod mock_website;
+
+use mock_website::run_aoc;
+
+#[test]
+fn fuzz_finds_no_panicking_parser() {
+    for day in 1..=25 {
+        let output = run_aoc(&["fuzz", &day.to_string(), "--inputs", "200"]);
+
+        assert!(
+            output.status.success(),
+            "day {day}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert_eq!(
+            String::from_utf8(output.stdout).unwrap(),
+            "The parser handles 200 mutated inputs.\n"
+        );
+    }
+}
@@ -0,0 +1,69 @@
+mod mock_website;
+
+use mock_website::{create_test_directory, run_aoc};
+use std::fs;
+
+// Sizes small enough for every day to be solved quickly in a debug build.
+const SMALL_SIZES: [(u8, usize); 25] = [
+    (1, 20),
+    (2, 50),
+    (3, 30),
+    (4, 50),
+    (5, 20),
+    (6, 100),
+    (7, 20),
+    (8, 10),
+    (9, 50),
+    (10, 20),
+    (11, 3),
+    (12, 30),
+    (13, 10),
+    (14, 10),
+    (15, 8),
+    (16, 8),
+    (17, 40),
+    (18, 50),
+    (19, 2),
+    (20, 50),
+    (21, 60),
+    (22, 2),
+    (23, 10),
+    (24, 10),
+    (25, 20),
+];
+
+#[test]
+fn generated_inputs_are_solved() {
+    let directory = create_test_directory("generate-solved");
+
+    for (day, size) in SMALL_SIZES {
+        for seed in ["1", "2"] {
+            let output = run_aoc(&[
+                "generate",
+                &day.to_string(),
+                "--size",
+                &size.to_string(),
+                "--seed",
+                seed,
+            ]);
+            assert!(output.status.success(), "day {day}, seed {seed}");
+
+            let input_path = directory.join(format!("day{day}-{seed}.txt"));
+            fs::write(&input_path, output.stdout).unwrap();
+            let output = run_aoc(&["run", &day.to_string(), input_path.to_str().unwrap()]);
+            assert!(
+                output.status.success(),
+                "day {day}, seed {seed}: {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+}
+
+#[test]
+fn generate_gives_the_same_input_for_a_seed() {
+    let generate = |seed: &str| run_aoc(&["generate", "16", "--size", "20", "--seed", seed]).stdout;
+
+    assert_eq!(generate("3"), generate("3"));
+    assert_ne!(generate("3"), generate("4"));
+}
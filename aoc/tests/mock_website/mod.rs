@@ -0,0 +1,135 @@
+// Each test file uses its own part of these helpers.
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+/// A local stand-in for the Advent of Code website, answering every request
+/// with `respond` and recording them.
+pub struct MockWebsite {
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockWebsite {
+    pub fn start(respond: impl Fn(&Request) -> (u16, String) + Send + 'static) -> MockWebsite {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests: Arc<Mutex<Vec<Request>>> = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded_requests = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let request = read_request(&mut BufReader::new(&mut stream));
+                let (status, body) = respond(&request);
+                recorded_requests.lock().unwrap().push(request);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        MockWebsite { url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn read_request(reader: &mut impl BufRead) -> Request {
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).unwrap();
+    let mut request_line_parts = request_line.split_whitespace();
+    let method = String::from(request_line_parts.next().unwrap());
+    let path = String::from(request_line_parts.next().unwrap());
+
+    let mut cookie = None;
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).unwrap();
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header.split_once(": ").unwrap();
+        match name.to_ascii_lowercase().as_str() {
+            "cookie" => cookie = Some(String::from(value)),
+            "content-length" => content_length = value.parse().unwrap(),
+            _ => {}
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).unwrap();
+
+    Request {
+        method,
+        path,
+        cookie,
+        body: String::from_utf8(body).unwrap(),
+    }
+}
+
+/// An empty directory of its own for the files written by a test.
+pub fn create_test_directory(name: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let directory = std::env::temp_dir().join(format!("aoc-{name}-{nanos}"));
+    std::fs::create_dir_all(&directory).unwrap();
+    directory
+}
+
+/// Runs the `aoc` binary with the given arguments, every website setting
+/// coming from them rather than from the environment.
+pub fn run_aoc(arguments: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(arguments)
+        .env_remove("AOC_CACHE")
+        .env_remove("AOC_SESSION")
+        .env_remove("AOC_URL")
+        .output()
+        .unwrap()
+}
+
+/// Runs the `aoc` binary like `run_aoc`, writing `input` to its standard input.
+pub fn run_aoc_with_stdin(arguments: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(arguments)
+        .env_remove("AOC_CACHE")
+        .env_remove("AOC_SESSION")
+        .env_remove("AOC_URL")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
@@ -0,0 +1,229 @@
+mod mock_website;
+
+use mock_website::{create_test_directory, run_aoc};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const WORKSPACE_MANIFEST: &str = r#"[workspace]
+resolver = "2"
+members = [
+    "aoc",
+    "common",
+    "day05",
+    "day17",
+    "day24",
+]
+"#;
+
+const RUNNER_MANIFEST: &str = r#"[package]
+name = "aoc"
+
+[dependencies]
+common = { path = "../common" }
+day05 = { path = "../day05" }
+day17 = { path = "../day17" }
+day24 = { path = "../day24" }
+"#;
+
+const FUZZ_MANIFEST: &str = r#"[package]
+name = "aoc-fuzz"
+
+[dependencies]
+libfuzzer-sys = "0.4"
+common = { path = "../common" }
+day05 = { path = "../day05" }
+day17 = { path = "../day17" }
+day24 = { path = "../day24" }
+
+[workspace]
+members = ["."]
+
+[[bin]]
+name = "day05"
+path = "fuzz_targets/day05.rs"
+test = false
+doc = false
+bench = false
+
+[[bin]]
+name = "day17"
+path = "fuzz_targets/day17.rs"
+test = false
+doc = false
+bench = false
+
+[[bin]]
+name = "day24"
+path = "fuzz_targets/day24.rs"
+test = false
+doc = false
+bench = false
+"#;
+
+const DAYS: &str = r#"pub const DAYS: &[Day] = &[
+    Day {
+        number: 5,
+        default_input: day05::DEFAULT_INPUT,
+        solve: common::solve::<day05::Day05>,
+        time: common::time::<day05::Day05>,
+        animate: None,
+        render: None,
+        generate: None,
+        compare: None,
+        fuzz: None,
+        stream: None,
+    },
+    Day {
+        number: 17,
+        default_input: day17::DEFAULT_INPUT,
+        solve: common::solve::<day17::Day17>,
+        time: common::time::<day17::Day17>,
+        animate: Some(common::animation::record::<day17::Day17>),
+        render: None,
+        generate: None,
+        compare: None,
+        fuzz: None,
+        stream: None,
+    },
+    Day {
+        number: 24,
+        default_input: day24::DEFAULT_INPUT,
+        solve: common::solve::<day24::Day24>,
+        time: common::time::<day24::Day24>,
+        animate: None,
+        render: None,
+        generate: None,
+        compare: None,
+        fuzz: None,
+        stream: None,
+    },
+];
+"#;
+
+// The files of a workspace that registering a day edits, with days 5, 17 and
+// 24 registered but none of their crates.
+fn create_workspace(name: &str) -> PathBuf {
+    let root = create_test_directory(name);
+    fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+    fs::create_dir_all(root.join("fuzz")).unwrap();
+    fs::write(root.join("Cargo.toml"), WORKSPACE_MANIFEST).unwrap();
+    fs::write(root.join("aoc").join("Cargo.toml"), RUNNER_MANIFEST).unwrap();
+    fs::write(root.join("aoc").join("src").join("days.rs"), DAYS).unwrap();
+    fs::write(root.join("fuzz").join("Cargo.toml"), FUZZ_MANIFEST).unwrap();
+    root
+}
+
+fn new_day(root: &Path, day: &str) -> bool {
+    run_aoc(&[
+        "new",
+        day,
+        "--root",
+        root.to_str().unwrap(),
+        "--cache",
+        root.join("cache").to_str().unwrap(),
+    ])
+    .status
+    .success()
+}
+
+#[test]
+fn new_creates_the_crate_of_the_day() {
+    let root = create_workspace("new-crate");
+    fs::create_dir_all(root.join("cache").join("2022")).unwrap();
+    fs::write(root.join("cache").join("2022").join("18.txt"), "1,1,1\n").unwrap();
+
+    assert!(new_day(&root, "18"));
+
+    let day_path = root.join("day18");
+    assert!(fs::read_to_string(day_path.join("Cargo.toml"))
+        .unwrap()
+        .contains("name = \"day18\""));
+    assert!(fs::read_to_string(day_path.join("src").join("lib.rs"))
+        .unwrap()
+        .contains("impl Solver for Day18"));
+    // The runner can solve the day before its parts are written.
+    let lib = fs::read_to_string(day_path.join("src").join("lib.rs")).unwrap();
+    assert!(lib.contains("Err(SolveError::new(\"part one is not solved yet\"))"));
+    assert!(!lib.contains("todo!"));
+    assert!(
+        fs::read_to_string(day_path.join("tests").join("examples.rs"))
+            .unwrap()
+            .contains("solve::<Day18>(EXAMPLE, Part::One)")
+    );
+    assert_eq!(
+        fs::read_to_string(day_path.join("example.txt")).unwrap(),
+        ""
+    );
+    assert_eq!(
+        fs::read_to_string(day_path.join("real.txt")).unwrap(),
+        "1,1,1\n"
+    );
+}
+
+#[test]
+fn new_registers_the_day_in_order() {
+    let root = create_workspace("new-register");
+
+    assert!(new_day(&root, "18"));
+    // Nothing was fetched to be the real input.
+    assert!(!root.join("day18").join("real.txt").exists());
+
+    let workspace_manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+    assert!(workspace_manifest.contains("    \"day17\",\n    \"day18\",\n    \"day24\",\n"));
+
+    let runner_manifest = fs::read_to_string(root.join("aoc").join("Cargo.toml")).unwrap();
+    assert!(runner_manifest.contains(
+        "day17 = { path = \"../day17\" }\nday18 = { path = \"../day18\" }\nday24 = { path = \"../day24\" }\n"
+    ));
+
+    let days = fs::read_to_string(root.join("aoc").join("src").join("days.rs")).unwrap();
+    let day_17_index = days.find("number: 17,").unwrap();
+    let day_18_index = days.find("number: 18,").unwrap();
+    let day_24_index = days.find("number: 24,").unwrap();
+    assert!(day_17_index < day_18_index && day_18_index < day_24_index);
+    assert!(days.contains("solve: common::solve::<day18::Day18>,"));
+    assert!(days.contains(
+        "time: common::time::<day18::Day18>,\n        animate: None,\n        render: None,\n        generate: None,\n        compare: None,\n        fuzz: None,\n        stream: None,"
+    ));
+
+    let fuzz_manifest = fs::read_to_string(root.join("fuzz").join("Cargo.toml")).unwrap();
+    assert!(fuzz_manifest.contains(
+        "day17 = { path = \"../day17\" }\nday18 = { path = \"../day18\" }\nday24 = { path = \"../day24\" }\n"
+    ));
+    assert!(fuzz_manifest.contains(
+        "bench = false\n\n[[bin]]\nname = \"day18\"\npath = \"fuzz_targets/day18.rs\"\ntest = false\ndoc = false\nbench = false\n\n[[bin]]\nname = \"day24\""
+    ));
+    assert!(
+        fs::read_to_string(root.join("fuzz").join("fuzz_targets").join("day18.rs"))
+            .unwrap()
+            .contains("common::fuzz::parse::<day18::Day18>(data)")
+    );
+}
+
+#[test]
+fn new_registers_the_last_day_after_the_others() {
+    let root = create_workspace("new-register-last");
+
+    assert!(new_day(&root, "25"));
+
+    let fuzz_manifest = fs::read_to_string(root.join("fuzz").join("Cargo.toml")).unwrap();
+    assert!(fuzz_manifest.ends_with(
+        "name = \"day24\"\npath = \"fuzz_targets/day24.rs\"\ntest = false\ndoc = false\nbench = false\n\n[[bin]]\nname = \"day25\"\npath = \"fuzz_targets/day25.rs\"\ntest = false\ndoc = false\nbench = false\n"
+    ));
+}
+
+#[test]
+fn new_refuses_existing_days() {
+    let root = create_workspace("new-existing");
+    let days = fs::read_to_string(root.join("aoc").join("src").join("days.rs")).unwrap();
+
+    assert!(!new_day(&root, "5"));
+    assert!(!root.join("day05").exists());
+    assert_eq!(
+        fs::read_to_string(root.join("aoc").join("src").join("days.rs")).unwrap(),
+        days
+    );
+
+    assert!(new_day(&root, "18"));
+    assert!(!new_day(&root, "18"));
+}
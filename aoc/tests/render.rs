@@ -0,0 +1,38 @@
+mod mock_website;
+
+use mock_website::{create_test_directory, run_aoc};
+use std::fs;
+
+const DAY_10_EXAMPLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../day10/example.txt");
+
+#[test]
+fn render_writes_the_image_after_the_answers() {
+    let image_path = create_test_directory("render-image").join("screen.ppm");
+
+    let output = run_aoc(&[
+        "run",
+        "10",
+        DAY_10_EXAMPLE,
+        "--part",
+        "1",
+        "--render",
+        image_path.to_str().unwrap(),
+    ]);
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "13140\n");
+    assert!(fs::read(&image_path)
+        .unwrap()
+        .starts_with(b"P6\n400 60\n255\n"));
+}
+
+#[test]
+fn render_refuses_days_without_a_rendering() {
+    let output = run_aoc(&["run", "1", DAY_10_EXAMPLE, "--render", "image.png"]);
+
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "Day 1 has no rendering.\n"
+    );
+}
@@ -0,0 +1,36 @@
+mod mock_website;
+
+use mock_website::{run_aoc, run_aoc_with_stdin};
+
+#[test]
+fn stream_solves_the_standard_input() {
+    let output = run_aoc_with_stdin(
+        &["run", "6", "-", "--stream"],
+        "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n",
+    );
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "7\n19\n");
+}
+
+#[test]
+fn stream_reports_invalid_inputs() {
+    let output = run_aoc_with_stdin(&["run", "1", "-", "--stream"], "1000\n20x0\n");
+
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "Invalid input, line 2, column 1: expected a number.\n"
+    );
+}
+
+#[test]
+fn stream_refuses_days_holding_their_whole_input() {
+    let output = run_aoc(&["run", "2", "--stream"]);
+
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "Day 2 cannot stream its input.\n"
+    );
+}
@@ -0,0 +1,119 @@
+mod mock_website;
+
+use mock_website::{create_test_directory, run_aoc, MockWebsite};
+use std::fs;
+use std::path::Path;
+use std::process::Output;
+
+// Day 1 answers 24000 to its first part on this input.
+const INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../day01/example.txt");
+
+const RIGHT_ANSWER: &str = "<article><p>That's the right answer! You are one gold star closer to collecting enough star fruit.</p></article>";
+const TOO_LOW: &str = "<article><p>That's not the right answer; your answer is too low. If you're stuck, make sure you're using the full input data.</p></article>";
+const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 42s left to wait.</p></article>";
+
+fn submit(cache: &Path, website: &MockWebsite) -> Output {
+    run_aoc(&[
+        "submit",
+        "1",
+        "1",
+        INPUT,
+        "--cache",
+        cache.to_str().unwrap(),
+        "--session",
+        "secret",
+        "--url",
+        &website.url,
+    ])
+}
+
+fn write_ledger(cache: &Path, ledger: &str) {
+    fs::create_dir_all(cache.join("2022")).unwrap();
+    fs::write(cache.join("2022").join("answers.json"), ledger).unwrap();
+}
+
+fn read_ledger(cache: &Path) -> serde_json::Value {
+    serde_json::from_str(&fs::read_to_string(cache.join("2022").join("answers.json")).unwrap())
+        .unwrap()
+}
+
+#[test]
+fn submit_posts_the_answer_and_records_the_verdict() {
+    let website = MockWebsite::start(|_| (200, String::from(RIGHT_ANSWER)));
+    let cache = create_test_directory("submit-right");
+
+    let output = submit(&cache, &website);
+
+    assert!(output.status.success());
+    let requests = website.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2022/day/1/answer");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=secret"));
+    assert_eq!(requests[0].body, "level=1&answer=24000");
+
+    let ledger = read_ledger(&cache);
+    assert_eq!(ledger[0]["day"], 1);
+    assert_eq!(ledger[0]["part"], 1);
+    assert_eq!(ledger[0]["answer"], "24000");
+    assert_eq!(ledger[0]["verdict"], "correct");
+}
+
+#[test]
+fn submit_never_resubmits_a_wrong_answer() {
+    let website = MockWebsite::start(|_| (200, String::from(TOO_LOW)));
+    let cache = create_test_directory("submit-wrong");
+
+    let first_output = submit(&cache, &website);
+    let second_output = submit(&cache, &website);
+
+    assert!(!first_output.status.success());
+    assert!(!second_output.status.success());
+    assert_eq!(website.requests().len(), 1);
+    assert_eq!(read_ledger(&cache)[0]["verdict"], "too_low");
+}
+
+#[test]
+fn submit_refuses_answers_beyond_a_known_bound() {
+    let website = MockWebsite::start(|_| (200, String::from(RIGHT_ANSWER)));
+
+    let too_high_cache = create_test_directory("submit-too-high");
+    write_ledger(
+        &too_high_cache,
+        r#"[{"day": 1, "part": 1, "answer": "20000", "verdict": "too_high", "submitted_at": 0}]"#,
+    );
+    let too_low_cache = create_test_directory("submit-too-low");
+    write_ledger(
+        &too_low_cache,
+        r#"[{"day": 1, "part": 1, "answer": "30000", "verdict": "too_low", "submitted_at": 0}]"#,
+    );
+
+    assert!(!submit(&too_high_cache, &website).status.success());
+    assert!(!submit(&too_low_cache, &website).status.success());
+    assert!(website.requests().is_empty());
+}
+
+#[test]
+fn submit_ignores_bounds_of_other_parts() {
+    let website = MockWebsite::start(|_| (200, String::from(RIGHT_ANSWER)));
+    let cache = create_test_directory("submit-other-part");
+    write_ledger(
+        &cache,
+        r#"[{"day": 1, "part": 2, "answer": "20000", "verdict": "too_high", "submitted_at": 0}]"#,
+    );
+
+    assert!(submit(&cache, &website).status.success());
+    assert_eq!(website.requests().len(), 1);
+}
+
+#[test]
+fn submit_retries_an_answer_given_too_recently() {
+    let website = MockWebsite::start(|_| (200, String::from(TOO_RECENT)));
+    let cache = create_test_directory("submit-too-recent");
+
+    submit(&cache, &website);
+    submit(&cache, &website);
+
+    assert_eq!(website.requests().len(), 2);
+    assert_eq!(read_ledger(&cache)[1]["verdict"], "too_recent");
+}
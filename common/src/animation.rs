@@ -0,0 +1,55 @@
+use crate::{ParseError, Solver};
+
+/// The frames of a simulation, each drawn as text, up to a maximum count so
+/// that long simulations are cut rather than filling the memory.
+#[derive(Debug, Clone)]
+pub struct Recording {
+    frames: Vec<String>,
+    max_frame_count: usize,
+}
+
+impl Recording {
+    pub fn new(max_frame_count: usize) -> Recording {
+        Recording {
+            frames: Vec::new(),
+            max_frame_count,
+        }
+    }
+
+    /// Adds a frame, only drawn when there is room left for it.
+    pub fn record(&mut self, draw_frame: impl FnOnce() -> String) {
+        if !self.is_full() {
+            self.frames.push(draw_frame());
+        }
+    }
+
+    /// Whether the simulation can stop, no more frame being recorded.
+    pub fn is_full(&self) -> bool {
+        self.frames.len() >= self.max_frame_count
+    }
+
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    pub fn into_frames(self) -> Vec<String> {
+        self.frames
+    }
+}
+
+/// A day whose simulation can be watched frame by frame.
+pub trait Animation: Solver {
+    fn animate(input: &Self::Input, recording: &mut Recording);
+}
+
+/// Parses the input and records up to `max_frame_count` frames of the day's
+/// simulation.
+pub fn record<A: Animation>(
+    input: &str,
+    max_frame_count: usize,
+) -> Result<Vec<String>, ParseError> {
+    let parsed_input = A::parse(input)?;
+    let mut recording = Recording::new(max_frame_count);
+    A::animate(&parsed_input, &mut recording);
+    Ok(recording.into_frames())
+}
@@ -0,0 +1,104 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A malformed puzzle input: where the parser stopped, and what it expected
+/// to find there. Lines and columns are numbered from one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Builds an error from the zero-based indexes of the line in the input and
+    /// of the character in the line.
+    pub fn at(line_index: usize, column_index: usize, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            line: line_index + 1,
+            column: column_index + 1,
+            expected: expected.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A well-formed puzzle input on which a part has no answer, such as a map
+/// with no path to its goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub reason: String,
+}
+
+impl SolveError {
+    pub fn new(reason: impl Into<String>) -> SolveError {
+        SolveError {
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.reason)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// Why a day gives no answer: its input is malformed, or a part has none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    Solve(SolveError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(error) => write!(f, "Invalid input, {error}."),
+            Error::Solve(error) => write!(f, "No answer, {error}."),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Error {
+        Error::Parse(error)
+    }
+}
+
+impl From<SolveError> for Error {
+    fn from(error: SolveError) -> Error {
+        Error::Solve(error)
+    }
+}
+
+/// Index in `line` at which `part`, a slice borrowed from `line`, starts.
+pub fn column_of(line: &str, part: &str) -> usize {
+    let offset = part.as_ptr() as usize - line.as_ptr() as usize;
+    line[..offset].chars().count()
+}
+
+/// Parses `part`, a slice of the line at `line_index`, as a number.
+pub fn parse_number<T: FromStr>(
+    line_index: usize,
+    line: &str,
+    part: &str,
+) -> Result<T, ParseError> {
+    part.parse::<T>()
+        .map_err(|_| ParseError::at(line_index, column_of(line, part), "a number"))
+}
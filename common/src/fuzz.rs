@@ -0,0 +1,136 @@
+use crate::minimise::minimise;
+use crate::{Generator, Random, Solver};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+// Words the mutations insert, likely to trip a parser: separators, numbers out
+// of range and characters of several bytes.
+const INSERTED_WORDS: [&str; 18] = [
+    "\n",
+    " ",
+    ",",
+    ":",
+    "=",
+    "-",
+    "[",
+    "]",
+    "#",
+    ".",
+    "0",
+    "-1",
+    "4294967296",
+    "99999999999999999999",
+    "é",
+    "→",
+    "🦀",
+    "\u{0}",
+];
+
+/// Parses the bytes given by a fuzzer. Any input must either be parsed or be
+/// rejected with a parse error, never make the parser panic.
+pub fn parse<S: Solver>(data: &[u8]) {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = S::parse(input);
+    }
+}
+
+/// An input on which the parser of a day panics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crash {
+    pub seed: u64,
+    pub message: String,
+    pub input: String,
+}
+
+impl fmt::Display for Crash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "The input of seed {} makes the parser panic: {}. Once minimised, the input is:\n{}",
+            self.seed, self.message, self.input
+        )
+    }
+}
+
+/// Parses generated inputs mutated at random, from the seeds following
+/// `first_seed`, and gives the first one the parser panics on, minimised. The
+/// inputs are at most a quarter of the real input's size, parsers being as
+/// exposed on small inputs.
+pub fn fuzz<G: Generator>(first_seed: u64, input_count: usize) -> Result<(), Crash> {
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let crash = (0..input_count).find_map(|index| {
+        let seed = first_seed.wrapping_add(index as u64);
+        let mut random = Random::new(seed);
+        let size = 1 + random.below(G::DEFAULT_SIZE / 4 + 1);
+        let generated_input = G::generate(&mut random, size);
+        let input = mutate(&mut random, &generated_input);
+        let message = get_panic_message::<G>(&input)?;
+
+        let input = minimise(&input, |candidate| {
+            get_panic_message::<G>(candidate).is_some()
+        });
+        // A parser panicking only now and then is reported with its first
+        // panic.
+        let message = get_panic_message::<G>(&input).unwrap_or(message);
+        Some(Crash {
+            seed,
+            message,
+            input,
+        })
+    });
+
+    panic::set_hook(previous_hook);
+    crash.map_or(Ok(()), Err)
+}
+
+// The message of the panic the parser raises on the input, if it does.
+fn get_panic_message<S: Solver>(input: &str) -> Option<String> {
+    catch_panic(|| S::parse(input)).err()
+}
+
+/// Runs the function, giving the message of the panic it raises if it does.
+pub(crate) fn catch_panic<T>(function: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(function)).map_err(|payload| {
+        match payload.downcast_ref::<&str>() {
+            Some(message) => String::from(*message),
+            None => payload
+                .downcast_ref::<String>()
+                .cloned()
+                .unwrap_or_else(|| String::from("no message")),
+        }
+    })
+}
+
+// Edits the input a few times at random places: inserting a word, removing or
+// duplicating a run of characters, replacing one by another of the input, or
+// cutting the input short.
+fn mutate(random: &mut Random, input: &str) -> String {
+    let mut characters: Vec<char> = input.chars().collect();
+
+    for _ in 0..1 + random.below(4) {
+        let start = random.below(characters.len() + 1);
+        let end = (start + 1 + random.below(8)).min(characters.len());
+        match random.below(9) {
+            0..=2 => {
+                let word = random.choose(&INSERTED_WORDS);
+                characters.splice(start..start, word.chars());
+            }
+            3 | 4 => {
+                characters.drain(start..end);
+            }
+            5 | 6 => {
+                let run = characters[start..end].to_vec();
+                let position = random.below(characters.len() + 1);
+                characters.splice(position..position, run);
+            }
+            7 if start < characters.len() => {
+                characters[start] = *random.choose(&characters);
+            }
+            _ => characters.truncate(start),
+        }
+    }
+
+    characters.into_iter().collect()
+}
@@ -0,0 +1,69 @@
+use crate::Solver;
+
+/// A small pseudo-random number generator (SplitMix64), so that a seed gives
+/// the same inputs on every machine and with every version of the crates.
+#[derive(Debug, Clone)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Random {
+        Random { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d049bb133111eb);
+        value ^ (value >> 31)
+    }
+
+    /// A number from 0 up to `bound` excluded.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "no number below 0");
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// A number from `low` to `high` included.
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high, "no number between {low} and {high}");
+        let span = high.abs_diff(low).wrapping_add(1);
+        if span == 0 {
+            // The whole range of `i64`, wrapping around.
+            return self.next_u64() as i64;
+        }
+        low.wrapping_add((self.next_u64() % span) as i64)
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.below(index + 1));
+        }
+    }
+}
+
+/// A day able to make up inputs of its puzzle, as valid as the real ones. The
+/// size is the scale of the input, in a unit told by each day: elves, lines,
+/// the side of a grid...
+pub trait Generator: Solver {
+    /// The size of the real input.
+    const DEFAULT_SIZE: usize;
+
+    fn generate(random: &mut Random, size: usize) -> String;
+}
+
+/// Makes up an input of the day from a seed, of its real input's size unless
+/// another one is given.
+pub fn generate<G: Generator>(seed: u64, size: Option<usize>) -> String {
+    G::generate(&mut Random::new(seed), size.unwrap_or(G::DEFAULT_SIZE))
+}
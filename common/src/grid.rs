@@ -0,0 +1,179 @@
+use crate::{ParseError, Point};
+use std::ops::{Index, IndexMut};
+
+/// A rectangle of cells, stored line after line. Cells are addressed by points
+/// whose `x` is the column and `y` the line, numbered from zero at the top left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    height: usize,
+    width: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(height: usize, width: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            height,
+            width,
+            cells: vec![value; height * width],
+        }
+    }
+
+    /// Parses one cell per character, every line of the input being a line of
+    /// the grid. `expected` describes the characters `parse_cell` accepts.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut parse_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut height = 0;
+        let mut width = 0;
+        let mut cells: Vec<T> = Vec::new();
+
+        for (line_index, line) in input.lines().enumerate() {
+            let mut line_width = 0;
+            for (column_index, char) in line.chars().enumerate() {
+                if line_index > 0 && column_index == width {
+                    return Err(ParseError::at(
+                        line_index,
+                        column_index,
+                        format!("a line of {width} cells"),
+                    ));
+                }
+                let cell = parse_cell(char)
+                    .ok_or_else(|| ParseError::at(line_index, column_index, expected))?;
+                cells.push(cell);
+                line_width += 1;
+            }
+
+            if line_index == 0 {
+                width = line_width;
+            }
+            if width == 0 {
+                return Err(ParseError::at(line_index, 0, expected));
+            }
+            if line_width != width {
+                return Err(ParseError::at(
+                    line_index,
+                    line_width,
+                    format!("a line of {width} cells"),
+                ));
+            }
+            height += 1;
+        }
+
+        if height == 0 {
+            return Err(ParseError::at(0, 0, expected));
+        }
+
+        Ok(Grid {
+            height,
+            width,
+            cells,
+        })
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn contains(&self, point: Point<usize>) -> bool {
+        point.y < self.height && point.x < self.width
+    }
+
+    pub fn get(&self, point: Point<usize>) -> Option<&T> {
+        if !self.contains(point) {
+            return None;
+        }
+        Some(&self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point<usize>) -> Option<&mut T> {
+        if !self.contains(point) {
+            return None;
+        }
+        Some(&mut self.cells[point.y * self.width + point.x])
+    }
+
+    /// Appends a line at the bottom of the grid.
+    pub fn push_line(&mut self, line: Vec<T>) {
+        assert_eq!(line.len(), self.width, "line of the wrong width");
+        self.cells.extend(line);
+        self.height += 1;
+    }
+
+    pub fn line(&self, line: usize) -> &[T] {
+        &self.cells[line * self.width..(line + 1) * self.width]
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, column: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(column < self.width, "column {column} out of the grid");
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    /// Every point of the grid, line after line.
+    pub fn points(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// The up to four cells sharing a side with the given one.
+    pub fn neighbours(&self, point: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        point
+            .neighbours()
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    /// The up to eight cells sharing a side or a corner with the given one.
+    pub fn neighbours_with_diagonals(
+        &self,
+        point: Point<usize>,
+    ) -> impl Iterator<Item = Point<usize>> + '_ {
+        point
+            .neighbours_with_diagonals()
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    pub fn map<U>(&self, map_cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            height: self.height,
+            width: self.width,
+            cells: self.cells.iter().map(map_cell).collect(),
+        }
+    }
+
+    /// Draws the grid one character per cell, the top line first.
+    pub fn render(&self, render_cell: impl Fn(&T) -> char) -> String {
+        self.lines()
+            .map(|line| line.iter().map(&render_cell).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<usize>) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} out of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point<usize>) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} out of the grid"))
+    }
+}
@@ -0,0 +1,286 @@
+use crate::{Grid, ParseError, Point, Solver};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::ops::{Index, IndexMut};
+use std::path::Path;
+use std::time::Duration;
+
+/// A color, by its red, green and blue components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    /// Goes from this color to the other one as `ratio` goes from 0 to 1.
+    pub fn blend(self, other: Rgb, ratio: f64) -> Rgb {
+        let ratio = ratio.clamp(0., 1.);
+        let mix =
+            |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * ratio).round() as u8;
+        Rgb(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+        )
+    }
+}
+
+/// A picture, indexed by `Point<usize>` like a grid, `x` being the column of
+/// the pixel and `y` its line from the top.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Image {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// Draws each cell of the grid as a square of `scale` pixels.
+    pub fn from_grid<T>(grid: &Grid<T>, scale: usize, color: impl Fn(&T) -> Rgb) -> Image {
+        let mut image = Image::new(grid.width() * scale, grid.height() * scale, Rgb::BLACK);
+        for cell in grid.points() {
+            image.fill_cell(cell, scale, color(&grid[cell]));
+        }
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixels(&self) -> &[Rgb] {
+        &self.pixels
+    }
+
+    pub fn contains(&self, pixel: Point<usize>) -> bool {
+        pixel.x < self.width && pixel.y < self.height
+    }
+
+    /// Fills the square of `scale` pixels standing for a cell, the parts of it
+    /// out of the image being left out.
+    pub fn fill_cell(&mut self, cell: Point<usize>, scale: usize, color: Rgb) {
+        for y in cell.y * scale..(cell.y + 1) * scale {
+            for x in cell.x * scale..(cell.x + 1) * scale {
+                if self.contains(Point::new(x, y)) {
+                    self[Point::new(x, y)] = color;
+                }
+            }
+        }
+    }
+
+    fn to_rgb_bytes(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|&Rgb(red, green, blue)| [red, green, blue])
+            .collect()
+    }
+}
+
+impl Index<Point<usize>> for Image {
+    type Output = Rgb;
+
+    fn index(&self, pixel: Point<usize>) -> &Rgb {
+        assert!(self.contains(pixel), "pixel {pixel:?} out of the image");
+        &self.pixels[pixel.y * self.width + pixel.x]
+    }
+}
+
+impl IndexMut<Point<usize>> for Image {
+    fn index_mut(&mut self, pixel: Point<usize>) -> &mut Rgb {
+        assert!(self.contains(pixel), "pixel {pixel:?} out of the image");
+        &mut self.pixels[pixel.y * self.width + pixel.x]
+    }
+}
+
+#[derive(Debug)]
+pub enum ExportError {
+    UnknownFormat(String),
+    NoFrame,
+    TooLarge(usize, usize),
+    Io(io::Error),
+    Encoding(String),
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExportError::UnknownFormat(path) => write!(
+                f,
+                "Cannot tell the format of {path}, expected a .png, .ppm or .gif file."
+            ),
+            ExportError::NoFrame => write!(f, "Nothing was drawn."),
+            ExportError::TooLarge(width, height) => {
+                write!(f, "An image of {width}x{height} pixels is too large.")
+            }
+            ExportError::Io(error) => write!(f, "Could not write the image: {error}"),
+            ExportError::Encoding(error) => write!(f, "Could not encode the image: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+impl From<io::Error> for ExportError {
+    fn from(error: io::Error) -> ExportError {
+        ExportError::Io(error)
+    }
+}
+
+/// Writes the frames to a file in the format of its extension: an animation
+/// for a `.gif`, and the last frame only, the final state, for a still `.png`
+/// or `.ppm`.
+pub fn save(path: &Path, frames: &[Image], frame_delay: Duration) -> Result<(), ExportError> {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase);
+    let Some(last_frame) = frames.last() else {
+        return Err(ExportError::NoFrame);
+    };
+
+    let mut writer = match extension.as_deref() {
+        Some("png" | "ppm" | "gif") => BufWriter::new(File::create(path)?),
+        _ => return Err(ExportError::UnknownFormat(path.display().to_string())),
+    };
+    match extension.as_deref() {
+        Some("png") => write_png(&mut writer, last_frame)?,
+        Some("ppm") => write_ppm(&mut writer, last_frame)?,
+        _ => write_gif(&mut writer, frames, frame_delay)?,
+    }
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Binary PPM, the simplest format around: a text header and the raw pixels.
+pub fn write_ppm(writer: &mut impl Write, image: &Image) -> Result<(), ExportError> {
+    write!(writer, "P6\n{} {}\n255\n", image.width, image.height)?;
+    writer.write_all(&image.to_rgb_bytes())?;
+    Ok(())
+}
+
+pub fn write_png(writer: &mut impl Write, image: &Image) -> Result<(), ExportError> {
+    let (width, height) = match (u32::try_from(image.width), u32::try_from(image.height)) {
+        (Ok(width), Ok(height)) if width > 0 && height > 0 => (width, height),
+        _ => return Err(ExportError::TooLarge(image.width, image.height)),
+    };
+
+    let mut encoder = png::Encoder::new(writer, width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut png_writer| png_writer.write_image_data(&image.to_rgb_bytes()))
+        .map_err(|error| ExportError::Encoding(error.to_string()))
+}
+
+/// An animated GIF looping forever, its frames having the size of the first
+/// one. Drawings rarely use more than the 256 colors a GIF palette holds, in
+/// which case they are shared by every frame; otherwise each frame gets a
+/// palette of its own, with the colors closest to its pixels.
+pub fn write_gif(
+    writer: &mut impl Write,
+    frames: &[Image],
+    frame_delay: Duration,
+) -> Result<(), ExportError> {
+    let Some(first_frame) = frames.first() else {
+        return Err(ExportError::NoFrame);
+    };
+    let (width, height) = match (
+        u16::try_from(first_frame.width),
+        u16::try_from(first_frame.height),
+    ) {
+        (Ok(width), Ok(height)) if width > 0 && height > 0 => (width, height),
+        _ => return Err(ExportError::TooLarge(first_frame.width, first_frame.height)),
+    };
+    if let Some(frame) = frames
+        .iter()
+        .find(|frame| (frame.width, frame.height) != (first_frame.width, first_frame.height))
+    {
+        return Err(ExportError::Encoding(format!(
+            "frames of {}x{} and {}x{} pixels",
+            first_frame.width, first_frame.height, frame.width, frame.height
+        )));
+    }
+    let encoding_error = |error: gif::EncodingError| ExportError::Encoding(error.to_string());
+
+    let mut palette_indexes: HashMap<Rgb, usize> = HashMap::new();
+    for &color in frames.iter().flat_map(|frame| frame.pixels.iter()) {
+        // One color too many is enough to give up on a shared palette.
+        if palette_indexes.len() > 256 {
+            break;
+        }
+        let palette_index = palette_indexes.len();
+        palette_indexes.entry(color).or_insert(palette_index);
+    }
+    let global_palette: Option<Vec<u8>> = (palette_indexes.len() <= 256).then(|| {
+        let mut palette = vec![0; 3 * palette_indexes.len()];
+        for (&Rgb(red, green, blue), &index) in palette_indexes.iter() {
+            palette[3 * index..3 * index + 3].copy_from_slice(&[red, green, blue]);
+        }
+        palette
+    });
+
+    let mut encoder = gif::Encoder::new(
+        writer,
+        width,
+        height,
+        global_palette.as_deref().unwrap_or(&[]),
+    )
+    .map_err(encoding_error)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(encoding_error)?;
+    // GIF delays are counted in hundredths of a second.
+    let delay = u16::try_from(frame_delay.as_millis() / 10).unwrap_or(u16::MAX);
+
+    for frame in frames {
+        let mut gif_frame = match global_palette {
+            Some(_) => gif::Frame {
+                width,
+                height,
+                buffer: Cow::Owned(
+                    frame
+                        .pixels
+                        .iter()
+                        .map(|color| palette_indexes[color] as u8)
+                        .collect(),
+                ),
+                ..gif::Frame::default()
+            },
+            None => gif::Frame::from_rgb_speed(width, height, &frame.to_rgb_bytes(), 10),
+        };
+        gif_frame.delay = delay;
+        encoder.write_frame(&gif_frame).map_err(encoding_error)?;
+    }
+
+    Ok(())
+}
+
+/// A day whose state can be drawn as pictures: a single one, or the frames of
+/// an animation.
+pub trait Render: Solver {
+    fn render(input: &Self::Input) -> Vec<Image>;
+}
+
+/// Parses the input and draws the day's pictures.
+pub fn render<R: Render>(input: &str) -> Result<Vec<Image>, ParseError> {
+    let parsed_input = R::parse(input)?;
+    Ok(R::render(&parsed_input))
+}
@@ -0,0 +1,94 @@
+use std::fmt;
+use std::fs;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, ErrorKind, Read};
+use std::path::{Path, PathBuf};
+
+/// Argument standing for the standard input instead of a file path.
+pub const STDIN_ARGUMENT: &str = "-";
+
+/// Path of the `real.txt` input stored next to the manifest of the crate
+/// invoking the macro.
+#[macro_export]
+macro_rules! default_input {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/real.txt")
+    };
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Unreadable(PathBuf, io::Error),
+    Stdin(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(f, "Input file {} not found.", path.display()),
+            InputError::Unreadable(path, error) => {
+                write!(f, "Could not read input file {}: {error}", path.display())
+            }
+            InputError::Stdin(error) => write!(f, "Could not read standard input: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// The default input of a day: its `real.txt`, or the input downloaded to the
+/// cache while there is no `real.txt` yet.
+pub fn get_default_path<'a>(real_path: &'a Path, cached_path: &'a Path) -> &'a Path {
+    if !real_path.exists() && cached_path.exists() {
+        cached_path
+    } else {
+        real_path
+    }
+}
+
+/// Reads the puzzle input from the given path, from the standard input when
+/// the argument is `-`, or from the day's default input when none is given.
+pub fn read_input(
+    argument: Option<&str>,
+    default_path: impl AsRef<Path>,
+) -> Result<String, InputError> {
+    match argument {
+        Some(STDIN_ARGUMENT) => {
+            let mut data = String::new();
+            io::stdin()
+                .read_to_string(&mut data)
+                .map_err(InputError::Stdin)?;
+            Ok(data)
+        }
+        Some(path) => read_file(Path::new(path)),
+        None => read_file(default_path.as_ref()),
+    }
+}
+
+/// Opens the puzzle input like `read_input`, to be read as it goes rather than
+/// at once.
+pub fn open_input(
+    argument: Option<&str>,
+    default_path: impl AsRef<Path>,
+) -> Result<Box<dyn BufRead>, InputError> {
+    let path = match argument {
+        Some(STDIN_ARGUMENT) => return Ok(Box::new(io::stdin().lock())),
+        Some(path) => Path::new(path),
+        None => default_path.as_ref(),
+    };
+
+    let file = File::open(path).map_err(|error| get_file_error(path, error))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|error| get_file_error(path, error))
+}
+
+fn get_file_error(path: &Path, error: io::Error) -> InputError {
+    match error.kind() {
+        ErrorKind::NotFound => InputError::NotFound(path.to_path_buf()),
+        _ => InputError::Unreadable(path.to_path_buf(), error),
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt::Display;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+pub mod animation;
+pub mod error;
+pub mod fuzz;
+pub mod generator;
+pub mod grid;
+pub mod image;
+pub mod input;
+mod minimise;
+pub mod operator;
+pub mod oracle;
+pub mod point;
+pub mod search;
+pub mod stream;
+#[cfg(feature = "test-support")]
+pub mod test_support;
+
+pub use animation::{Animation, Recording};
+pub use error::{Error, ParseError, SolveError};
+pub use generator::{Generator, Random};
+pub use grid::Grid;
+pub use image::{Image, Render, Rgb};
+pub use operator::Operator;
+pub use oracle::Oracle;
+pub use point::{CompassDirection, Direction, Point, Point3};
+pub use stream::Stream;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn from_number(number: u8) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// A day of the calendar: its input is parsed once, then each part of the
+/// puzzle computes its answer from the parsed input, or tells why it has none.
+pub trait Solver {
+    type Input;
+    type AnswerOne: Display;
+    type AnswerTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(input: &Self::Input) -> Result<Self::AnswerOne, SolveError>;
+
+    fn part_two(input: &Self::Input) -> Result<Self::AnswerTwo, SolveError>;
+}
+
+/// Parses the raw input and solves the requested part, for callers that only
+/// need the answer as text.
+pub fn solve<S: Solver>(input: &str, part: Part) -> Result<String, Error> {
+    let parsed_input = S::parse(input)?;
+
+    Ok(match part {
+        Part::One => S::part_one(&parsed_input)?.to_string(),
+        Part::Two => S::part_two(&parsed_input)?.to_string(),
+    })
+}
+
+/// Wall time spent in each phase of a day.
+#[derive(Debug, Clone, Copy)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+}
+
+/// Parses the raw input then solves both parts, timing each phase on its own.
+pub fn time<S: Solver>(input: &str) -> Result<Timings, Error> {
+    let start = Instant::now();
+    let parsed_input = black_box(S::parse(input)?);
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part_one(&parsed_input)?);
+    let part_one = start.elapsed();
+
+    let start = Instant::now();
+    black_box(S::part_two(&parsed_input)?);
+    let part_two = start.elapsed();
+
+    Ok(Timings {
+        parse,
+        part_one,
+        part_two,
+    })
+}
@@ -0,0 +1,47 @@
+/// Removes chunks of lines, then chunks of characters of each line, as long as
+/// the input still fails. Every line of the minimised input ends with a line
+/// break. The input is given back whole when the minimised one does not fail,
+/// as its line breaks may be what made it fail.
+pub fn minimise(input: &str, still_fails: impl Fn(&str) -> bool) -> String {
+    let join_lines =
+        |lines: &[String]| -> String { lines.iter().map(|line| format!("{line}\n")).collect() };
+
+    let mut lines: Vec<String> = input.lines().map(String::from).collect();
+    lines = shrink(lines, |lines| still_fails(&join_lines(lines)));
+    for line_index in 0..lines.len() {
+        let characters: Vec<char> = lines[line_index].chars().collect();
+        let characters = shrink(characters, |characters| {
+            let mut candidate_lines = lines.clone();
+            candidate_lines[line_index] = characters.iter().collect();
+            still_fails(&join_lines(&candidate_lines))
+        });
+        lines[line_index] = characters.into_iter().collect();
+    }
+
+    let minimised_input = join_lines(&lines);
+    if still_fails(&minimised_input) {
+        minimised_input
+    } else {
+        String::from(input)
+    }
+}
+
+// Removes chunks of items while the condition holds, the chunks being halved
+// down to single items.
+fn shrink<T: Clone>(mut items: Vec<T>, still_holds: impl Fn(&[T]) -> bool) -> Vec<T> {
+    let mut chunk_size = items.len().div_ceil(2);
+    while chunk_size > 0 {
+        let mut start = 0;
+        while start < items.len() {
+            let end = (start + chunk_size).min(items.len());
+            let candidate: Vec<T> = [&items[..start], &items[end..]].concat();
+            if still_holds(&candidate) {
+                items = candidate;
+            } else {
+                start = end;
+            }
+        }
+        chunk_size /= 2;
+    }
+    items
+}
@@ -0,0 +1,31 @@
+/// One of the four arithmetic operations the monkeys work out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Operator {
+    /// The operator written as `+`, `-`, `*` or `/`.
+    pub fn from_symbol(symbol: &str) -> Option<Operator> {
+        match symbol {
+            "+" => Some(Operator::Add),
+            "-" => Some(Operator::Subtract),
+            "*" => Some(Operator::Multiply),
+            "/" => Some(Operator::Divide),
+            _ => None,
+        }
+    }
+
+    /// Works out the operation, unless it overflows or divides by zero.
+    pub fn checked_apply(self, left: i64, right: i64) -> Option<i64> {
+        match self {
+            Operator::Add => left.checked_add(right),
+            Operator::Subtract => left.checked_sub(right),
+            Operator::Multiply => left.checked_mul(right),
+            Operator::Divide => left.checked_div(right),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use crate::fuzz::catch_panic;
+use crate::minimise::minimise;
+use crate::{Generator, ParseError, Part, Random, SolveError};
+use std::fmt;
+use std::panic;
+
+/// A day keeping its straightforward solution next to the optimised one, as a
+/// reference the optimised one must agree with. Each phase defaults to the
+/// optimised one, so that a day only writes the phases it made faster.
+pub trait Oracle: Generator {
+    /// The size the compared inputs grow to, unless another one is given.
+    const MAX_COMPARED_SIZE: usize = Self::DEFAULT_SIZE;
+
+    fn naive_parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse(input)
+    }
+
+    fn naive_part_one(input: &Self::Input) -> Result<Self::AnswerOne, SolveError> {
+        Self::part_one(input)
+    }
+
+    fn naive_part_two(input: &Self::Input) -> Result<Self::AnswerTwo, SolveError> {
+        Self::part_two(input)
+    }
+}
+
+/// An input on which the naive and the optimised solutions disagree, about a
+/// part or, when `part` is `None`, about whether the input is valid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub seed: u64,
+    pub part: Option<Part>,
+    pub naive_answer: String,
+    pub answer: String,
+    pub input: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let phase = match self.part {
+            Some(part) => format!("part {}", part.number()),
+            None => String::from("the parsing"),
+        };
+        write!(
+            f,
+            "The input of seed {} makes the naive and optimised solutions disagree on {phase}: \
+             {} against {}. Once minimised, the input is:\n{}",
+            self.seed, self.naive_answer, self.answer, self.input
+        )
+    }
+}
+
+/// Compares the naive and optimised solutions on inputs generated from the
+/// seeds following `first_seed`, and gives the first disagreement with its
+/// input minimised. A solution panicking disagrees with the other whatever it
+/// gives. The inputs grow from tiny to the given size, or to the day's
+/// `MAX_COMPARED_SIZE`, so that a disagreement shows up on a small one first.
+pub fn compare<O: Oracle>(
+    first_seed: u64,
+    input_count: usize,
+    size: Option<usize>,
+) -> Result<(), Mismatch> {
+    let max_size = size.unwrap_or(O::MAX_COMPARED_SIZE).max(1);
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mismatch = (0..input_count).find_map(|index| {
+        let seed = first_seed.wrapping_add(index as u64);
+        let size = 1 + index * max_size / input_count.max(1);
+        let input = O::generate(&mut Random::new(seed), size);
+
+        let disagreement = find_disagreement::<O>(&input)?;
+        let input = minimise(&input, |candidate| {
+            find_disagreement::<O>(candidate).is_some_and(|candidate_disagreement| {
+                candidate_disagreement.part == disagreement.part
+                    && candidate_disagreement.is_panic == disagreement.is_panic
+            })
+        });
+        // Solutions disagreeing only now and then are reported with their
+        // first disagreement.
+        let disagreement = find_disagreement::<O>(&input).unwrap_or(disagreement);
+        Some(Mismatch {
+            seed,
+            part: disagreement.part,
+            naive_answer: disagreement.naive_answer,
+            answer: disagreement.answer,
+            input,
+        })
+    });
+
+    panic::set_hook(previous_hook);
+    mismatch.map_or(Ok(()), Err)
+}
+
+// The phase on which the solutions disagree, with the answers as printed.
+struct Disagreement {
+    part: Option<Part>,
+    naive_answer: String,
+    answer: String,
+    is_panic: bool,
+}
+
+// Answers are compared as they are printed. The inputs on which a solution
+// panics are only minimised while it keeps panicking, as the minimising easily
+// makes inputs the puzzle would never give.
+fn find_disagreement<O: Oracle>(input: &str) -> Option<Disagreement> {
+    let naive_input = catch_panic(|| O::naive_parse(input));
+    let parsed_input = catch_panic(|| O::parse(input));
+    let (naive_input, parsed_input) = match (naive_input, parsed_input) {
+        (Ok(Ok(naive_input)), Ok(Ok(parsed_input))) => (naive_input, parsed_input),
+        (Ok(Err(_)), Ok(Err(_))) => return None,
+        (naive_input, parsed_input) => {
+            let describe =
+                |parsed_input: &Result<Result<O::Input, ParseError>, String>| match parsed_input {
+                    Ok(Ok(_)) => String::from("a valid input"),
+                    Ok(Err(_)) => String::from("an invalid input"),
+                    Err(message) => format!("a panic ({message})"),
+                };
+            return Some(Disagreement {
+                part: None,
+                naive_answer: describe(&naive_input),
+                answer: describe(&parsed_input),
+                is_panic: naive_input.is_err() || parsed_input.is_err(),
+            });
+        }
+    };
+
+    let naive_answer = catch_panic(|| O::naive_part_one(&naive_input));
+    let answer = catch_panic(|| O::part_one(&parsed_input));
+    if let Some(disagreement) = compare_answers(Part::One, naive_answer, answer) {
+        return Some(disagreement);
+    }
+
+    let naive_answer = catch_panic(|| O::naive_part_two(&naive_input));
+    let answer = catch_panic(|| O::part_two(&parsed_input));
+    compare_answers(Part::Two, naive_answer, answer)
+}
+
+fn compare_answers<A: fmt::Display>(
+    part: Part,
+    naive_answer: Result<Result<A, SolveError>, String>,
+    answer: Result<Result<A, SolveError>, String>,
+) -> Option<Disagreement> {
+    let is_panic = naive_answer.is_err() || answer.is_err();
+    let naive_answer = describe_answer(naive_answer);
+    let answer = describe_answer(answer);
+
+    (is_panic || naive_answer != answer).then_some(Disagreement {
+        part: Some(part),
+        naive_answer,
+        answer,
+        is_panic,
+    })
+}
+
+fn describe_answer(answer: Result<Result<impl fmt::Display, SolveError>, String>) -> String {
+    match answer {
+        Ok(Ok(answer)) => answer.to_string(),
+        Ok(Err(error)) => format!("no answer ({error})"),
+        Err(message) => format!("a panic ({message})"),
+    }
+}
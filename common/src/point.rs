@@ -0,0 +1,381 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Integer types usable as the coordinates of a `Point`.
+pub trait Coordinate: Copy + Debug + Ord + Hash + Add<Output = Self> + Sub<Output = Self> {
+    /// Type of the distances between two coordinates.
+    type Distance: Copy + Ord + Add<Output = Self::Distance>;
+
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    fn abs_diff(self, other: Self) -> Self::Distance;
+
+    /// `-1`, `0` or `1` following the sign of the coordinate, unsigned types
+    /// never being negative.
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_coordinate {
+    (|$value:ident| $signum:expr, $($coordinate:ty => $distance:ty),*) => {$(
+        impl Coordinate for $coordinate {
+            type Distance = $distance;
+
+            const ZERO: $coordinate = 0;
+            const ONE: $coordinate = 1;
+
+            fn checked_add(self, other: $coordinate) -> Option<$coordinate> {
+                <$coordinate>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: $coordinate) -> Option<$coordinate> {
+                <$coordinate>::checked_sub(self, other)
+            }
+
+            fn abs_diff(self, other: $coordinate) -> $distance {
+                <$coordinate>::abs_diff(self, other)
+            }
+
+            fn signum(self) -> $coordinate {
+                let $value = self;
+                $signum
+            }
+        }
+    )*};
+}
+
+impl_coordinate!(|coordinate| coordinate.signum(), i32 => u32, i64 => u64, isize => usize);
+impl_coordinate!(|coordinate| coordinate.min(1), u32 => u32, u64 => u64, usize => usize);
+
+/// The four directions of a grid. The y axis points down, as the lines of an
+/// input do: going `Up` decreases `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.turn_left().opposite()
+    }
+}
+
+/// The eight directions of a grid, diagonals included, `North` being `Up`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompassDirection {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl CompassDirection {
+    pub const ALL: [CompassDirection; 8] = [
+        CompassDirection::North,
+        CompassDirection::NorthEast,
+        CompassDirection::East,
+        CompassDirection::SouthEast,
+        CompassDirection::South,
+        CompassDirection::SouthWest,
+        CompassDirection::West,
+        CompassDirection::NorthWest,
+    ];
+
+    /// The one or two directions of a grid this direction is made of.
+    pub fn components(self) -> &'static [Direction] {
+        match self {
+            CompassDirection::North => &[Direction::Up],
+            CompassDirection::NorthEast => &[Direction::Up, Direction::Right],
+            CompassDirection::East => &[Direction::Right],
+            CompassDirection::SouthEast => &[Direction::Down, Direction::Right],
+            CompassDirection::South => &[Direction::Down],
+            CompassDirection::SouthWest => &[Direction::Down, Direction::Left],
+            CompassDirection::West => &[Direction::Left],
+            CompassDirection::NorthWest => &[Direction::Up, Direction::Left],
+        }
+    }
+}
+
+impl From<Direction> for CompassDirection {
+    fn from(direction: Direction) -> CompassDirection {
+        match direction {
+            Direction::Up => CompassDirection::North,
+            Direction::Down => CompassDirection::South,
+            Direction::Left => CompassDirection::West,
+            Direction::Right => CompassDirection::East,
+        }
+    }
+}
+
+/// A point of the plane, or the vector between two points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Point<T> {
+        Point { x, y }
+    }
+
+    /// Converts both coordinates to a type holding all their values.
+    pub fn cast<U: From<T>>(self) -> Point<U> {
+        Point::new(U::from(self.x), U::from(self.y))
+    }
+
+    /// Converts both coordinates to another type, if they fit in it.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Point<U>> {
+        Some(Point::new(
+            U::try_from(self.x).ok()?,
+            U::try_from(self.y).ok()?,
+        ))
+    }
+}
+
+impl<T: Coordinate> Point<T> {
+    pub const ORIGIN: Point<T> = Point::new(T::ZERO, T::ZERO);
+
+    pub fn manhattan_distance(self, other: Point<T>) -> T::Distance {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev_distance(self, other: Point<T>) -> T::Distance {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// The vector whose coordinates are the signs of this one's, one step
+    /// towards its direction on each axis.
+    pub fn signum(self) -> Point<T> {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The next point in the given direction, unless it is out of the range of
+    /// the coordinates.
+    pub fn checked_step(self, direction: impl Into<CompassDirection>) -> Option<Point<T>> {
+        direction
+            .into()
+            .components()
+            .iter()
+            .try_fold(self, |point, direction| match direction {
+                Direction::Up => Some(Point::new(point.x, point.y.checked_sub(T::ONE)?)),
+                Direction::Down => Some(Point::new(point.x, point.y.checked_add(T::ONE)?)),
+                Direction::Left => Some(Point::new(point.x.checked_sub(T::ONE)?, point.y)),
+                Direction::Right => Some(Point::new(point.x.checked_add(T::ONE)?, point.y)),
+            })
+    }
+
+    pub fn step(self, direction: impl Into<CompassDirection>) -> Point<T> {
+        self.checked_step(direction)
+            .unwrap_or_else(|| panic!("{self:?} is at the edge of its coordinates"))
+    }
+
+    /// The up to four points sharing a side with this one.
+    pub fn neighbours(self) -> impl Iterator<Item = Point<T>> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.checked_step(direction))
+    }
+
+    /// The up to eight points sharing a side or a corner with this one.
+    pub fn neighbours_with_diagonals(self) -> impl Iterator<Item = Point<T>> {
+        CompassDirection::ALL
+            .into_iter()
+            .filter_map(move |direction| self.checked_step(direction))
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Point<T> {
+        Point::new(x, y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Point<T>) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Point<T>) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, factor: T) -> Point<T> {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Point<T> {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+/// A point of the space, or the vector between two points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+
+    /// Converts the three coordinates to a type holding all their values.
+    pub fn cast<U: From<T>>(self) -> Point3<U> {
+        Point3::new(U::from(self.x), U::from(self.y), U::from(self.z))
+    }
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub const ORIGIN: Point3<T> = Point3::new(T::ZERO, T::ZERO, T::ZERO);
+
+    pub fn manhattan_distance(self, other: Point3<T>) -> T::Distance {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /// The up to six points sharing a face with this one.
+    pub fn neighbours(self) -> impl Iterator<Item = Point3<T>> {
+        let Point3 { x, y, z } = self;
+        [
+            x.checked_sub(T::ONE).map(|x| Point3::new(x, y, z)),
+            x.checked_add(T::ONE).map(|x| Point3::new(x, y, z)),
+            y.checked_sub(T::ONE).map(|y| Point3::new(x, y, z)),
+            y.checked_add(T::ONE).map(|y| Point3::new(x, y, z)),
+            z.checked_sub(T::ONE).map(|z| Point3::new(x, y, z)),
+            z.checked_add(T::ONE).map(|z| Point3::new(x, y, z)),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    /// The up to twenty-six points sharing a face, an edge or a corner with
+    /// this one.
+    pub fn neighbours_with_diagonals(self) -> impl Iterator<Item = Point3<T>> {
+        const OFFSETS: [i8; 3] = [-1, 0, 1];
+        let shift = |value: T, offset: i8| match offset {
+            -1 => value.checked_sub(T::ONE),
+            1 => value.checked_add(T::ONE),
+            _ => Some(value),
+        };
+
+        OFFSETS.into_iter().flat_map(move |x_offset| {
+            OFFSETS.into_iter().flat_map(move |y_offset| {
+                OFFSETS.into_iter().filter_map(move |z_offset| {
+                    if (x_offset, y_offset, z_offset) == (0, 0, 0) {
+                        return None;
+                    }
+                    Some(Point3::new(
+                        shift(self.x, x_offset)?,
+                        shift(self.y, y_offset)?,
+                        shift(self.z, z_offset)?,
+                    ))
+                })
+            })
+        })
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Point3<T> {
+        Point3::new(x, y, z)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point3<T> {
+    type Output = Point3<T>;
+
+    fn add(self, other: Point3<T>) -> Point3<T> {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Point3<T>;
+
+    fn sub(self, other: Point3<T>) -> Point3<T> {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point3<T> {
+    type Output = Point3<T>;
+
+    fn mul(self, factor: T) -> Point3<T> {
+        Point3::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point3<T> {
+    type Output = Point3<T>;
+
+    fn neg(self) -> Point3<T> {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
@@ -0,0 +1,150 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The cheapest way found from the start of a search to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    /// Every node walked through, from the start to the goal included.
+    pub nodes: Vec<N>,
+}
+
+/// Breadth-first search, every step costing one. `neighbours` lists the nodes
+/// reachable in one step from a node.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited_nodes: Vec<(N, Option<usize>)> = vec![(start.clone(), None)];
+    let mut seen_nodes: HashSet<N> = HashSet::from([start]);
+    let mut node_queue: VecDeque<usize> = VecDeque::from([0]);
+
+    while let Some(node_index) = node_queue.pop_front() {
+        if is_goal(&visited_nodes[node_index].0) {
+            let nodes = get_path_nodes(&visited_nodes, node_index);
+            return Some(Path {
+                cost: nodes.len() - 1,
+                nodes,
+            });
+        }
+
+        for neighbour in neighbours(&visited_nodes[node_index].0) {
+            if seen_nodes.insert(neighbour.clone()) {
+                visited_nodes.push((neighbour, Some(node_index)));
+                node_queue.push_back(visited_nodes.len() - 1);
+            }
+        }
+    }
+
+    None
+}
+
+/// Every node reachable from the start, the start included.
+pub fn reachable<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> HashSet<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen_nodes: HashSet<N> = HashSet::from([start.clone()]);
+    let mut node_queue: VecDeque<N> = VecDeque::from([start]);
+
+    while let Some(node) = node_queue.pop_front() {
+        for neighbour in neighbours(&node) {
+            if seen_nodes.insert(neighbour.clone()) {
+                node_queue.push_back(neighbour);
+            }
+        }
+    }
+
+    seen_nodes
+}
+
+/// Dijkstra's search. `neighbours` lists the nodes reachable in one step from
+/// a node, with the cost of that step.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search: Dijkstra's search guided by `heuristic`, which must never
+/// overestimate the cost left from a node to the closest goal for the path
+/// found to be the cheapest. The default value of `C` is the zero cost.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut visited_nodes: Vec<(N, Option<usize>)> = vec![(start.clone(), None)];
+    let mut best_costs: HashMap<N, C> = HashMap::from([(start.clone(), C::default())]);
+    let mut node_queue: BinaryHeap<Reverse<(C, C, usize)>> = BinaryHeap::new();
+    node_queue.push(Reverse((heuristic(&start), C::default(), 0)));
+
+    while let Some(Reverse((_, cost, node_index))) = node_queue.pop() {
+        let node = visited_nodes[node_index].0.clone();
+        // The node was queued again since, with a lower cost.
+        if best_costs[&node] < cost {
+            continue;
+        }
+
+        if is_goal(&node) {
+            return Some(Path {
+                cost,
+                nodes: get_path_nodes(&visited_nodes, node_index),
+            });
+        }
+
+        for (neighbour, step_cost) in neighbours(&node) {
+            let neighbour_cost = cost + step_cost;
+            if best_costs
+                .get(&neighbour)
+                .is_some_and(|&best_cost| best_cost <= neighbour_cost)
+            {
+                continue;
+            }
+            best_costs.insert(neighbour.clone(), neighbour_cost);
+
+            let estimated_cost = neighbour_cost + heuristic(&neighbour);
+            visited_nodes.push((neighbour, Some(node_index)));
+            node_queue.push(Reverse((
+                estimated_cost,
+                neighbour_cost,
+                visited_nodes.len() - 1,
+            )));
+        }
+    }
+
+    None
+}
+
+// Walks back the parents of the node up to the start of the search.
+fn get_path_nodes<N: Clone>(visited_nodes: &[(N, Option<usize>)], node_index: usize) -> Vec<N> {
+    let mut nodes: Vec<N> = Vec::new();
+    let mut current_index = Some(node_index);
+    while let Some(index) = current_index {
+        nodes.push(visited_nodes[index].0.clone());
+        current_index = visited_nodes[index].1;
+    }
+    nodes.reverse();
+    nodes
+}
@@ -0,0 +1,120 @@
+use crate::{ParseError, SolveError, Solver};
+use std::fmt;
+use std::io::{self, BufRead};
+
+/// A day solving both parts in a single pass over its input, keeping a state
+/// bounded whatever the size of the input, so that inputs larger than the
+/// memory can be piped to it. Only the days able to keep such a state
+/// implement it, or to keep no more than their parsed input without its text,
+/// the others reading their whole input.
+pub trait Stream: Solver {
+    fn stream(reader: impl BufRead) -> Result<(Self::AnswerOne, Self::AnswerTwo), StreamError>;
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Read(io::Error),
+    Parse(ParseError),
+    Solve(SolveError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Read(error) => write!(f, "Could not read the input: {error}"),
+            StreamError::Parse(error) => write!(f, "Invalid input, {error}."),
+            StreamError::Solve(error) => write!(f, "No answer, {error}."),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> StreamError {
+        StreamError::Read(error)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(error: ParseError) -> StreamError {
+        StreamError::Parse(error)
+    }
+}
+
+impl From<SolveError> for StreamError {
+    fn from(error: SolveError) -> StreamError {
+        StreamError::Solve(error)
+    }
+}
+
+/// Streams the input of a day, giving the answers of both parts as printed.
+pub fn solve<S: Stream>(reader: &mut dyn BufRead) -> Result<(String, String), StreamError> {
+    let (answer_one, answer_two) = S::stream(reader)?;
+    Ok((answer_one.to_string(), answer_two.to_string()))
+}
+
+/// Reads the input a line at a time, along with the index of the line.
+pub fn lines(reader: impl BufRead) -> impl Iterator<Item = Result<(usize, String), StreamError>> {
+    reader
+        .lines()
+        .enumerate()
+        .map(|(line_index, line)| Ok((line_index, line?)))
+}
+
+/// Reads an input made of symbols, such as a datastream, without ever holding
+/// it. `on_symbol` takes each byte until the trailing whitespace, and tells
+/// whether it is one of the symbols described by `expected`. Gives the number
+/// of symbols read.
+pub fn read_symbols(
+    mut reader: impl BufRead,
+    expected: &str,
+    mut on_symbol: impl FnMut(u8) -> bool,
+) -> Result<usize, StreamError> {
+    let mut symbol_count = 0;
+    let mut is_trailing = false;
+    let mut line_index = 0;
+    let mut column_index = 0;
+
+    loop {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            return Ok(symbol_count);
+        }
+
+        for &byte in buffer {
+            if !is_trailing && on_symbol(byte) {
+                symbol_count += 1;
+            } else if byte.is_ascii_whitespace() {
+                is_trailing = true;
+            } else {
+                return Err(ParseError::at(line_index, column_index, expected).into());
+            }
+
+            // Columns count characters, the bytes continuing one being skipped.
+            if byte == b'\n' {
+                line_index += 1;
+                column_index = 0;
+            } else if byte & 0b1100_0000 != 0b1000_0000 {
+                column_index += 1;
+            }
+        }
+
+        let length = buffer.len();
+        reader.consume(length);
+    }
+}
+
+/// Reads the symbols of a whole input like `read_symbols`, so that its parser
+/// reports errors where streaming it would.
+pub fn parse_symbols(
+    input: &str,
+    expected: &str,
+    on_symbol: impl FnMut(u8) -> bool,
+) -> Result<usize, ParseError> {
+    read_symbols(input.as_bytes(), expected, on_symbol).map_err(|error| match error {
+        StreamError::Parse(error) => error,
+        StreamError::Read(error) => unreachable!("reading from memory failed: {error}"),
+        StreamError::Solve(error) => unreachable!("reading symbols solves nothing: {error}"),
+    })
+}
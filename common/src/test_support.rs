@@ -0,0 +1,41 @@
+// Helpers for the tests of the days, left out of the library unless the
+// `test-support` feature is enabled by their dev-dependencies.
+
+use crate::stream::{self, Stream, StreamError};
+
+/// Checks that streaming the input gives the answers of solving it whole, or
+/// the same parse error or first missing answer, for the tests of the days
+/// implementing `Stream`.
+pub fn assert_stream_matches<S: Stream>(input: &str) {
+    let streamed_answers = stream::solve::<S>(&mut input.as_bytes());
+
+    match S::parse(input) {
+        Ok(parsed_input) => {
+            let answers = S::part_one(&parsed_input).and_then(|answer_one| {
+                Ok((
+                    answer_one.to_string(),
+                    S::part_two(&parsed_input)?.to_string(),
+                ))
+            });
+            match (streamed_answers, answers) {
+                (Ok(streamed_answers), Ok(answers)) => {
+                    assert_eq!(streamed_answers, answers, "Input:\n{input}")
+                }
+                (Err(StreamError::Solve(streamed_error)), Err(error)) => {
+                    assert_eq!(streamed_error, error, "Input:\n{input}")
+                }
+                (streamed_answers, answers) => panic!(
+                    "Streaming the input gives {streamed_answers:?} rather than {answers:?}. Input:\n{input}"
+                ),
+            }
+        }
+        Err(error) => match streamed_answers {
+            Err(StreamError::Parse(streamed_error)) => {
+                assert_eq!(streamed_error, error, "Input:\n{input}")
+            }
+            streamed_answers => panic!(
+                "Streaming the invalid input gives {streamed_answers:?} rather than {error:?}. Input:\n{input}"
+            ),
+        },
+    }
+}
@@ -0,0 +1,18 @@
+use common::Recording;
+
+#[test]
+fn recording_stops_drawing_once_full() {
+    let mut recording = Recording::new(2);
+    let mut drawn_frame_count = 0;
+
+    for frame_index in 0..5 {
+        recording.record(|| {
+            drawn_frame_count += 1;
+            format!("frame {frame_index}")
+        });
+    }
+
+    assert!(recording.is_full());
+    assert_eq!(drawn_frame_count, 2);
+    assert_eq!(recording.frames(), ["frame 0", "frame 1"]);
+}
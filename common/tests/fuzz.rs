@@ -0,0 +1,51 @@
+use common::error::parse_number;
+use common::fuzz::fuzz;
+use common::{Generator, ParseError, Random, SolveError, Solver};
+
+// Reads numbers, its parser panicking when the last one ends the input.
+struct UnendedLine;
+
+impl Solver for UnendedLine {
+    type Input = Vec<u32>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        if !input.is_empty() && !input.ends_with('\n') {
+            panic!("the last line is not ended");
+        }
+        input
+            .lines()
+            .enumerate()
+            .map(|(line_index, line)| parse_number(line_index, line, line))
+            .collect()
+    }
+
+    fn part_one(numbers: &Vec<u32>) -> Result<u32, SolveError> {
+        Ok(numbers.iter().sum())
+    }
+
+    fn part_two(numbers: &Vec<u32>) -> Result<u32, SolveError> {
+        Ok(numbers.iter().sum())
+    }
+}
+
+impl Generator for UnendedLine {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| random.below(10 * size).to_string())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+#[test]
+fn fuzz_keeps_the_input_its_minimising_fixes() {
+    let crash = fuzz::<UnendedLine>(0, 100).unwrap_err();
+
+    // Every line of a minimised input is ended, so the input is kept whole.
+    assert_eq!(crash.message, "the last line is not ended");
+    assert!(!crash.input.ends_with('\n'));
+}
@@ -0,0 +1,65 @@
+use common::{Grid, ParseError, Point};
+
+fn parse_digits(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(input, "a digit", |char| char.to_digit(10))
+}
+
+#[test]
+fn parse_reads_lines_of_cells() {
+    let grid = parse_digits("123\n456\n").unwrap();
+
+    assert_eq!((grid.height(), grid.width()), (2, 3));
+    assert_eq!(grid[Point::new(0, 1)], 4);
+    assert_eq!(grid.get(Point::new(3, 0)), None);
+    assert_eq!(grid.get(Point::new(0, 2)), None);
+}
+
+#[test]
+fn parse_rejects_malformed_grids() {
+    assert_eq!(parse_digits("12\n3x"), Err(ParseError::at(1, 1, "a digit")));
+    assert_eq!(
+        parse_digits("12\n3"),
+        Err(ParseError::at(1, 1, "a line of 2 cells"))
+    );
+    assert_eq!(
+        parse_digits("12\n345"),
+        Err(ParseError::at(1, 2, "a line of 2 cells"))
+    );
+    assert_eq!(parse_digits(""), Err(ParseError::at(0, 0, "a digit")));
+}
+
+#[test]
+fn neighbours_stay_in_the_grid() {
+    let grid = parse_digits("123\n456\n789").unwrap();
+
+    assert_eq!(
+        grid.neighbours(Point::new(0, 0)).collect::<Vec<_>>(),
+        vec![Point::new(0, 1), Point::new(1, 0)]
+    );
+    assert_eq!(grid.neighbours(Point::new(1, 1)).count(), 4);
+    assert_eq!(
+        grid.neighbours_with_diagonals(Point::new(2, 2))
+            .collect::<Vec<_>>(),
+        vec![Point::new(2, 1), Point::new(1, 2), Point::new(1, 1)]
+    );
+    assert_eq!(grid.neighbours_with_diagonals(Point::new(1, 1)).count(), 8);
+}
+
+#[test]
+fn lines_and_columns_iterate_in_order() {
+    let grid = parse_digits("123\n456").unwrap();
+
+    assert_eq!(grid.line(1), &[4, 5, 6]);
+    assert_eq!(grid.column(2).collect::<Vec<_>>(), vec![&3, &6]);
+    assert_eq!(grid.points().nth(4), Some(Point::new(1, 1)));
+}
+
+#[test]
+fn render_draws_one_character_per_cell() {
+    let grid = parse_digits("10\n01").unwrap();
+
+    assert_eq!(
+        grid.render(|&cell| if cell == 1 { '#' } else { '.' }),
+        "#.\n.#"
+    );
+}
@@ -0,0 +1,67 @@
+use common::image::{write_gif, write_png, write_ppm};
+use common::{Image, Point, Rgb};
+use std::time::Duration;
+
+const RED: Rgb = Rgb(255, 0, 0);
+
+fn draw_test_image(color: Rgb) -> Image {
+    let mut image = Image::new(3, 2, Rgb::WHITE);
+    image.fill_cell(Point::new(1, 0), 2, color);
+    image
+}
+
+#[test]
+fn fill_cell_paints_a_scaled_square_within_the_image() {
+    let image = draw_test_image(RED);
+
+    // The square of the cell spans columns 2 and 3, the latter being cut.
+    assert_eq!(
+        image.pixels(),
+        [Rgb::WHITE, Rgb::WHITE, RED, Rgb::WHITE, Rgb::WHITE, RED]
+    );
+}
+
+#[test]
+fn ppm_holds_the_raw_pixels() {
+    let mut ppm: Vec<u8> = Vec::new();
+    write_ppm(&mut ppm, &draw_test_image(RED)).unwrap();
+
+    let mut expected = b"P6\n3 2\n255\n".to_vec();
+    for _line in 0..2 {
+        expected.extend([255, 255, 255, 255, 255, 255, 255, 0, 0]);
+    }
+    assert_eq!(ppm, expected);
+}
+
+#[test]
+fn png_decodes_back_to_the_image() {
+    let mut png_bytes: Vec<u8> = Vec::new();
+    write_png(&mut png_bytes, &draw_test_image(RED)).unwrap();
+
+    let mut reader = png::Decoder::new(png_bytes.as_slice()).read_info().unwrap();
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut pixels).unwrap();
+
+    assert_eq!((info.width, info.height), (3, 2));
+    assert_eq!(&pixels[..9], [255, 255, 255, 255, 255, 255, 255, 0, 0]);
+}
+
+#[test]
+fn gif_holds_every_frame() {
+    let frames = [draw_test_image(RED), draw_test_image(Rgb::BLACK)];
+    let mut gif_bytes: Vec<u8> = Vec::new();
+    write_gif(&mut gif_bytes, &frames, Duration::from_millis(50)).unwrap();
+
+    let mut options = gif::DecodeOptions::new();
+    options.set_color_output(gif::ColorOutput::RGBA);
+    let mut decoder = options.read_info(gif_bytes.as_slice()).unwrap();
+    let mut decoded_frames: Vec<Vec<u8>> = Vec::new();
+    while let Some(frame) = decoder.read_next_frame().unwrap() {
+        assert_eq!(frame.delay, 5);
+        decoded_frames.push(frame.buffer.to_vec());
+    }
+
+    assert_eq!(decoded_frames.len(), 2);
+    assert_eq!(&decoded_frames[0][8..12], [255, 0, 0, 255]);
+    assert_eq!(&decoded_frames[1][8..12], [0, 0, 0, 255]);
+}
@@ -0,0 +1,47 @@
+use common::input::{get_default_path, read_input};
+use std::fs;
+use std::path::PathBuf;
+
+fn create_test_directory(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("common-input-{name}"));
+    let _ = fs::remove_dir_all(&directory);
+    fs::create_dir_all(&directory).unwrap();
+    directory
+}
+
+#[test]
+fn real_input_is_read_by_default() {
+    let directory = create_test_directory("real");
+    let real_path = directory.join("real.txt");
+    let cached_path = directory.join("01.txt");
+    fs::write(&real_path, "real").unwrap();
+    fs::write(&cached_path, "cached").unwrap();
+
+    let default_path = get_default_path(&real_path, &cached_path);
+    assert_eq!(read_input(None, default_path).unwrap(), "real");
+}
+
+#[test]
+fn cached_input_is_read_without_real_input() {
+    let directory = create_test_directory("cached");
+    let real_path = directory.join("real.txt");
+    let cached_path = directory.join("01.txt");
+    fs::write(&cached_path, "cached").unwrap();
+
+    let default_path = get_default_path(&real_path, &cached_path);
+    assert_eq!(read_input(None, default_path).unwrap(), "cached");
+}
+
+#[test]
+fn missing_real_input_is_reported_without_cached_input() {
+    let directory = create_test_directory("missing");
+    let real_path = directory.join("real.txt");
+    let cached_path = directory.join("01.txt");
+
+    let default_path = get_default_path(&real_path, &cached_path);
+    let error = read_input(None, default_path).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        format!("Input file {} not found.", real_path.display())
+    );
+}
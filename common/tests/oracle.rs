@@ -0,0 +1,190 @@
+use common::error::parse_number;
+use common::oracle::compare;
+use common::{Generator, Oracle, ParseError, Part, Random, SolveError, Solver};
+
+// Sums numbers, its optimised part one forgetting the numbers above 7.
+struct Sum;
+
+impl Solver for Sum {
+    type Input = Vec<u32>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(line_index, line)| parse_number(line_index, line, line))
+            .collect()
+    }
+
+    fn part_one(numbers: &Vec<u32>) -> Result<u32, SolveError> {
+        Ok(numbers.iter().filter(|&&number| number <= 7).sum())
+    }
+
+    fn part_two(numbers: &Vec<u32>) -> Result<u32, SolveError> {
+        Ok(numbers.iter().sum())
+    }
+}
+
+impl Generator for Sum {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{}\n", random.below(10 * size)))
+            .collect()
+    }
+}
+
+impl Oracle for Sum {
+    fn naive_part_one(numbers: &Vec<u32>) -> Result<u32, SolveError> {
+        Ok(numbers.iter().sum())
+    }
+}
+
+// Agrees with its naive solution, which is the default one.
+struct FixedSum;
+
+impl Solver for FixedSum {
+    type Input = Vec<u32>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        Sum::parse(input)
+    }
+
+    fn part_one(numbers: &Vec<u32>) -> Result<u32, SolveError> {
+        Sum::part_two(numbers)
+    }
+
+    fn part_two(numbers: &Vec<u32>) -> Result<u32, SolveError> {
+        Sum::part_two(numbers)
+    }
+}
+
+impl Generator for FixedSum {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        Sum::generate(random, size)
+    }
+}
+
+impl Oracle for FixedSum {}
+
+#[test]
+fn compare_minimises_the_first_disagreement() {
+    let mismatch = compare::<Sum>(0, 100, None).unwrap_err();
+
+    assert_eq!(mismatch.part, Some(Part::One));
+    // A single digit above 7 is all it takes.
+    assert!(mismatch.input == "8\n" || mismatch.input == "9\n");
+    assert_eq!(mismatch.answer, "0");
+    assert_eq!(mismatch.naive_answer, mismatch.input.trim_end());
+}
+
+#[test]
+fn compare_accepts_agreeing_solutions() {
+    assert_eq!(compare::<FixedSum>(0, 100, None), Ok(()));
+}
+
+// Panics on its optimised part two when a number reaches 50.
+struct PanickingSum;
+
+impl Solver for PanickingSum {
+    type Input = Vec<u32>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        Sum::parse(input)
+    }
+
+    fn part_one(numbers: &Vec<u32>) -> Result<u32, SolveError> {
+        Sum::part_two(numbers)
+    }
+
+    fn part_two(numbers: &Vec<u32>) -> Result<u32, SolveError> {
+        if numbers.iter().any(|&number| number >= 50) {
+            panic!("a number reaches 50");
+        }
+        Sum::part_two(numbers)
+    }
+}
+
+impl Generator for PanickingSum {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        Sum::generate(random, size)
+    }
+}
+
+impl Oracle for PanickingSum {
+    fn naive_part_two(numbers: &Vec<u32>) -> Result<u32, SolveError> {
+        Sum::part_two(numbers)
+    }
+}
+
+#[test]
+fn compare_reports_a_panicking_solution() {
+    let mismatch = compare::<PanickingSum>(0, 100, None).unwrap_err();
+
+    assert_eq!(mismatch.part, Some(Part::Two));
+    assert_eq!(mismatch.answer, "a panic (a number reaches 50)");
+    let number: u32 = mismatch.input.trim_end().parse().unwrap();
+    assert!(number >= 50);
+    assert_eq!(mismatch.naive_answer, number.to_string());
+}
+
+// Its optimised parser rejects Windows line breaks, which the naive one reads.
+struct CarriageReturnSum;
+
+impl Solver for CarriageReturnSum {
+    type Input = Vec<u32>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        match input.find('\r') {
+            Some(column_index) => Err(ParseError::at(0, column_index, "a digit")),
+            None => Sum::parse(input),
+        }
+    }
+
+    fn part_one(numbers: &Vec<u32>) -> Result<u32, SolveError> {
+        Sum::part_two(numbers)
+    }
+
+    fn part_two(numbers: &Vec<u32>) -> Result<u32, SolveError> {
+        Sum::part_two(numbers)
+    }
+}
+
+impl Generator for CarriageReturnSum {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        Sum::generate(random, size).replace('\n', "\r\n")
+    }
+}
+
+impl Oracle for CarriageReturnSum {
+    fn naive_parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        Sum::parse(input)
+    }
+}
+
+#[test]
+fn compare_keeps_the_input_its_minimising_fixes() {
+    let mismatch = compare::<CarriageReturnSum>(0, 100, None).unwrap_err();
+
+    // Every line of a minimised input ends with a bare line break, so the
+    // input is kept whole.
+    assert_eq!(mismatch.part, None);
+    assert_eq!(mismatch.naive_answer, "a valid input");
+    assert_eq!(mismatch.answer, "an invalid input");
+    assert!(mismatch.input.ends_with("\r\n"));
+}
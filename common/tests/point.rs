@@ -0,0 +1,91 @@
+use common::{CompassDirection, Direction, Point, Point3};
+
+#[test]
+fn distances_between_points() {
+    let point_a = Point::new(2_i32, 18);
+    let point_b = Point::new(-2, 15);
+
+    assert_eq!(point_a.manhattan_distance(point_b), 7_u32);
+    assert_eq!(point_a.chebyshev_distance(point_b), 4);
+    assert_eq!(point_b.manhattan_distance(point_a), 7);
+}
+
+#[test]
+fn arithmetic_on_points() {
+    let mut point = Point::new(3_i64, -4);
+
+    assert_eq!(point + Point::new(1, 1), Point::new(4, -3));
+    assert_eq!(point - Point::new(1, 1), Point::new(2, -5));
+    assert_eq!(point * 2, Point::new(6, -8));
+    assert_eq!(-point, Point::new(-3, 4));
+    assert_eq!(point.signum(), Point::new(1, -1));
+
+    point += Point::new(-3, 4);
+    assert_eq!(point, Point::ORIGIN);
+}
+
+#[test]
+fn steps_follow_the_lines_of_the_input() {
+    let point = Point::new(1_usize, 1);
+
+    assert_eq!(point.step(Direction::Up), Point::new(1, 0));
+    assert_eq!(point.step(Direction::Right), Point::new(2, 1));
+    assert_eq!(point.step(CompassDirection::SouthWest), Point::new(0, 2));
+    assert_eq!(Point::new(0_usize, 3).checked_step(Direction::Left), None);
+    assert_eq!(Point::<usize>::ORIGIN.neighbours().count(), 2);
+    assert_eq!(point.neighbours_with_diagonals().count(), 8);
+}
+
+#[test]
+fn directions_turn() {
+    assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    assert_eq!(Direction::Left.opposite(), Direction::Right);
+}
+
+#[test]
+fn conversions_between_coordinate_types() {
+    assert_eq!(Point::new(3_u32, 4).cast::<i64>(), Point::new(3_i64, 4));
+    assert_eq!(
+        Point::new(3_i32, 4).try_cast::<usize>(),
+        Some(Point::new(3, 4))
+    );
+    assert_eq!(Point::new(-3_i32, 4).try_cast::<usize>(), None);
+    assert_eq!(Point::from((1, 2)), Point::new(1, 2));
+}
+
+#[test]
+fn points_of_the_space_share_faces() {
+    let point = Point3::new(0_u32, 4, 2);
+
+    let neighbours: Vec<Point3<u32>> = point.neighbours().collect();
+    assert_eq!(
+        neighbours,
+        [
+            Point3::new(1, 4, 2),
+            Point3::new(0, 3, 2),
+            Point3::new(0, 5, 2),
+            Point3::new(0, 4, 1),
+            Point3::new(0, 4, 3),
+        ]
+    );
+    assert_eq!(point.manhattan_distance(Point3::ORIGIN), 6);
+    assert_eq!(point + Point3::new(1, 1, 1) - point, Point3::new(1, 1, 1));
+    assert_eq!(-Point3::new(1_i32, -2, 0) * 3, Point3::new(-3, 6, 0));
+}
+
+#[test]
+fn points_of_the_space_share_edges_and_corners() {
+    assert_eq!(
+        Point3::new(4_i32, 4, 2).neighbours_with_diagonals().count(),
+        26
+    );
+    assert_eq!(
+        Point3::new(0_u32, 4, 2).neighbours_with_diagonals().count(),
+        17
+    );
+    assert_eq!(
+        Point3::new(i32::MIN, 0, 0).cast::<i64>() - Point3::new(1, 0, 0),
+        Point3::new(i32::MIN as i64 - 1, 0, 0)
+    );
+}
@@ -0,0 +1,104 @@
+use common::search::{astar, bfs, dijkstra, reachable};
+use common::{Grid, Point};
+
+const MAZE: &str = "\
+..#....
+.##.##.
+....#..
+.##...#
+...#...";
+
+fn parse_maze() -> Grid<bool> {
+    Grid::parse(MAZE, "`.` or `#`", |char| match char {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+    .unwrap()
+}
+
+#[test]
+fn bfs_finds_a_shortest_path() {
+    let walls = parse_maze();
+    let goal = Point::new(6, 4);
+
+    let path = bfs(
+        Point::new(0, 0),
+        |&point| {
+            walls
+                .neighbours(point)
+                .filter(|&neighbour| !walls[neighbour])
+        },
+        |&point| point == goal,
+    )
+    .unwrap();
+
+    assert_eq!(path.cost, 10);
+    assert_eq!(path.nodes.len(), 11);
+    assert_eq!(path.nodes.first(), Some(&Point::new(0, 0)));
+    assert_eq!(path.nodes.last(), Some(&goal));
+    for (node, next_node) in path.nodes.iter().zip(path.nodes.iter().skip(1)) {
+        assert_eq!(node.manhattan_distance(*next_node), 1);
+        assert!(!walls[*next_node]);
+    }
+}
+
+#[test]
+fn searches_report_unreachable_goals() {
+    let walls = parse_maze();
+
+    let path = bfs(
+        Point::new(0, 0),
+        |&point| {
+            walls
+                .neighbours(point)
+                .filter(|&neighbour| !walls[neighbour])
+        },
+        |&point| point == Point::new(2, 0),
+    );
+
+    assert_eq!(path, None);
+}
+
+#[test]
+fn reachable_floods_every_open_cell() {
+    let walls = parse_maze();
+
+    let open_cells = reachable(Point::new(0, 0), |&point| {
+        walls
+            .neighbours(point)
+            .filter(|&neighbour| !walls[neighbour])
+    });
+
+    assert_eq!(open_cells.len(), 25);
+    assert!(open_cells.iter().all(|&point| !walls[point]));
+}
+
+#[test]
+fn dijkstra_and_astar_follow_the_cheapest_steps() {
+    // Going up costs ten, any other step costs one.
+    let neighbours = |&point: &Point<i32>| {
+        [
+            (Point::new(point.x + 1, point.y), 1),
+            (Point::new(point.x - 1, point.y), 1),
+            (Point::new(point.x, point.y + 1), 1),
+            (Point::new(point.x, point.y - 1), 10),
+        ]
+        .into_iter()
+        .filter(|(neighbour, _)| neighbour.x.abs() <= 5 && neighbour.y.abs() <= 5)
+    };
+    let goal = Point::new(3, -2);
+
+    let dijkstra_path = dijkstra(Point::ORIGIN, neighbours, |&point| point == goal).unwrap();
+    let astar_path = astar(
+        Point::ORIGIN,
+        neighbours,
+        |&point| point.manhattan_distance(goal),
+        |&point| point == goal,
+    )
+    .unwrap();
+
+    assert_eq!(dijkstra_path.cost, 23);
+    assert_eq!(astar_path.cost, 23);
+    assert_eq!(astar_path.nodes.len(), 6);
+}
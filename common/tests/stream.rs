@@ -0,0 +1,40 @@
+use common::stream::{read_symbols, StreamError};
+use common::ParseError;
+use std::io::BufReader;
+
+fn read_digits(input: &str) -> Result<usize, StreamError> {
+    // A tiny buffer, so that the input is read across several of them.
+    let reader = BufReader::with_capacity(3, input.as_bytes());
+    read_symbols(reader, "a digit", |byte| byte.is_ascii_digit())
+}
+
+#[test]
+fn symbols_are_counted_until_the_trailing_whitespace() {
+    assert_eq!(read_digits("0123456789\n").unwrap(), 10);
+    assert_eq!(read_digits("42 \n\n").unwrap(), 2);
+    assert_eq!(read_digits("").unwrap(), 0);
+}
+
+#[test]
+fn invalid_symbols_are_reported_where_they_are() {
+    let Err(StreamError::Parse(error)) = read_digits("0123x5\n") else {
+        panic!("The input is invalid.");
+    };
+    assert_eq!(error, ParseError::at(0, 4, "a digit"));
+
+    // On the second line, after a space.
+    let Err(StreamError::Parse(error)) = read_digits("0123\n 4\n") else {
+        panic!("The input is invalid.");
+    };
+    assert_eq!(error, ParseError::at(1, 1, "a digit"));
+
+    // Columns count characters rather than bytes.
+    let Err(StreamError::Parse(error)) = read_digits("01é\n") else {
+        panic!("The input is invalid.");
+    };
+    assert_eq!(error, ParseError::at(0, 2, "a digit"));
+    let Err(StreamError::Parse(error)) = read_digits("é\n0é 1\n") else {
+        panic!("The input is invalid.");
+    };
+    assert_eq!(error, ParseError::at(0, 0, "a digit"));
+}
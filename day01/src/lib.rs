@@ -0,0 +1,142 @@
+use common::error::parse_number;
+use common::stream::{lines, StreamError};
+use common::{Generator, ParseError, Random, SolveError, Solver, Stream};
+use std::io::BufRead;
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    type Input = Vec<u32>;
+    type AnswerOne = u32;
+    type AnswerTwo = u64;
+
+    fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        let mut elves_calories: Vec<u32> = Vec::new();
+        let mut calorie_counter = CalorieCounter::default();
+
+        for (line_index, line_value) in input.lines().enumerate() {
+            elves_calories.extend(calorie_counter.read_line(line_index, line_value)?);
+        }
+        elves_calories.extend(calorie_counter.finish());
+
+        Ok(elves_calories)
+    }
+
+    fn part_one(elves_calories: &Vec<u32>) -> Result<u32, SolveError> {
+        Ok(elves_calories.iter().copied().max().unwrap_or(0))
+    }
+
+    fn part_two(elves_calories: &Vec<u32>) -> Result<u64, SolveError> {
+        let maximums_sorted_calories =
+            elves_calories
+                .iter()
+                .fold([0; 3], |maximums_sorted_calories, &calories| {
+                    update_maximum_calories(maximums_sorted_calories, calories)
+                });
+
+        Ok(sum_calories(maximums_sorted_calories))
+    }
+}
+
+// Only the three elves carrying the most calories are kept.
+impl Stream for Day01 {
+    fn stream(reader: impl BufRead) -> Result<(u32, u64), StreamError> {
+        let mut maximums_sorted_calories = [0; 3];
+        let mut calorie_counter = CalorieCounter::default();
+
+        for line in lines(reader) {
+            let (line_index, line_value) = line?;
+            if let Some(calories) = calorie_counter.read_line(line_index, &line_value)? {
+                maximums_sorted_calories =
+                    update_maximum_calories(maximums_sorted_calories, calories);
+            }
+        }
+        if let Some(calories) = calorie_counter.finish() {
+            maximums_sorted_calories = update_maximum_calories(maximums_sorted_calories, calories);
+        }
+
+        Ok((
+            maximums_sorted_calories[0],
+            sum_calories(maximums_sorted_calories),
+        ))
+    }
+}
+
+// The size is the number of elves.
+impl Generator for Day01 {
+    const DEFAULT_SIZE: usize = 250;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        let elves: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let food_count = random.between(1, 15);
+                (0..food_count)
+                    .map(|_| random.between(1000, 60000).to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            })
+            .collect();
+
+        elves.join("\n\n") + "\n"
+    }
+}
+
+// Adds up the calories of an elf line by line, until the blank line ending it.
+#[derive(Default)]
+struct CalorieCounter {
+    calories: u32,
+    is_counting: bool,
+}
+
+impl CalorieCounter {
+    // Gives the calories of the elf the line ends, if it does.
+    fn read_line(
+        &mut self,
+        line_index: usize,
+        line_value: &str,
+    ) -> Result<Option<u32>, ParseError> {
+        if line_value.is_empty() {
+            return Ok(std::mem::take(self).finish());
+        }
+
+        let calories = parse_number::<u32>(line_index, line_value, line_value)?;
+        self.calories = self.calories.checked_add(calories).ok_or_else(|| {
+            ParseError::at(
+                line_index,
+                0,
+                format!("calories keeping the elf's total up to {}", u32::MAX),
+            )
+        })?;
+        self.is_counting = true;
+        Ok(None)
+    }
+
+    // Gives the calories of the elf being counted, if any.
+    fn finish(self) -> Option<u32> {
+        self.is_counting.then_some(self.calories)
+    }
+}
+
+fn update_maximum_calories(maximums_sorted_calories: [u32; 3], new_calories: u32) -> [u32; 3] {
+    let mut calories_to_insert = new_calories;
+    let mut new_maximums_sorted_calories = [0; 3];
+
+    for item in maximums_sorted_calories.into_iter().enumerate() {
+        let (index, calories) = item;
+        if calories_to_insert > calories {
+            new_maximums_sorted_calories[index] = calories_to_insert;
+            calories_to_insert = maximums_sorted_calories[index];
+        } else {
+            new_maximums_sorted_calories[index] = maximums_sorted_calories[index];
+        }
+    }
+
+    new_maximums_sorted_calories
+}
+
+// Three elves can carry more calories together than a single one may.
+fn sum_calories(maximums_sorted_calories: [u32; 3]) -> u64 {
+    maximums_sorted_calories.into_iter().map(u64::from).sum()
+}
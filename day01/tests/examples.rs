@@ -0,0 +1,20 @@
+use common::{solve, Part};
+use day01::Day01;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day01>(EXAMPLE, Part::One).unwrap(), "24000");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day01>(EXAMPLE, Part::Two).unwrap(), "45000");
+}
+
+#[test]
+fn top_three_above_32_bits() {
+    let input = "4000000000\n\n4000000000\n\n4000000000\n";
+    assert_eq!(solve::<Day01>(input, Part::Two).unwrap(), "12000000000");
+}
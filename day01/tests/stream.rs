@@ -0,0 +1,30 @@
+use common::generator;
+use common::test_support::assert_stream_matches;
+use common::Solver;
+use day01::Day01;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn streaming_the_example_gives_the_same_answers() {
+    assert_stream_matches::<Day01>(EXAMPLE);
+}
+
+#[test]
+fn streaming_generated_inputs_gives_the_same_answers() {
+    for seed in 0..50 {
+        assert_stream_matches::<Day01>(&generator::generate::<Day01>(seed, Some(20)));
+    }
+}
+
+#[test]
+fn streaming_reports_the_same_parse_error() {
+    let input = "1000\n2000\n\n20x0\n";
+    assert!(Day01::parse(input).is_err());
+    assert_stream_matches::<Day01>(input);
+}
+
+#[test]
+fn streaming_top_three_above_32_bits() {
+    assert_stream_matches::<Day01>("4000000000\n\n4000000000\n\n4000000000\n");
+}
@@ -0,0 +1,102 @@
+use common::{Generator, ParseError, Random, SolveError, Solver};
+
+const ENEMY: [char; 3] = ['A', 'B', 'C'];
+const ALLY: [char; 3] = ['X', 'Y', 'Z'];
+// Rock, Paper, Scissors
+
+const LOOSE: char = 'X';
+const DRAW: char = 'Y';
+const WIN: char = 'Z';
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    type Input = Vec<(char, char)>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<(char, char)>, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(line_index, line_value)| {
+                let characters: Vec<char> = line_value.chars().collect();
+
+                if characters.len() != 3 || characters[1] != ' ' {
+                    return Err(ParseError::at(line_index, 0, "a round like `A Y`"));
+                }
+                if !ENEMY.contains(&characters[0]) {
+                    return Err(ParseError::at(line_index, 0, "`A`, `B` or `C`"));
+                }
+                if !ALLY.contains(&characters[2]) {
+                    return Err(ParseError::at(line_index, 2, "`X`, `Y` or `Z`"));
+                }
+
+                Ok((characters[0], characters[2]))
+            })
+            .collect()
+    }
+
+    fn part_one(rounds: &Vec<(char, char)>) -> Result<usize, SolveError> {
+        Ok(rounds
+            .iter()
+            .map(|&(enemy, ally)| get_shape_score(ally, enemy))
+            .sum())
+    }
+
+    fn part_two(rounds: &Vec<(char, char)>) -> Result<usize, SolveError> {
+        Ok(rounds
+            .iter()
+            .map(|&(enemy, goal)| get_score(goal, enemy))
+            .sum())
+    }
+}
+
+// The size is the number of rounds.
+impl Generator for Day02 {
+    const DEFAULT_SIZE: usize = 2500;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{} {}\n", random.choose(&ENEMY), random.choose(&ALLY)))
+            .collect()
+    }
+}
+
+fn get_shape_score(ally: char, enemy: char) -> usize {
+    let enemy_index = index_of(&ENEMY, enemy);
+    let ally_index = index_of(&ALLY, ally);
+
+    get_round_score(ally_index, enemy_index)
+}
+
+fn get_score(goal: char, enemy: char) -> usize {
+    let enemy_index = index_of(&ENEMY, enemy);
+    let ally_index = match goal {
+        LOOSE => (enemy_index + 2) % 3,
+        DRAW => enemy_index,
+        WIN => (enemy_index + 1) % 3,
+        _ => 0,
+    };
+
+    get_round_score(ally_index, enemy_index)
+}
+
+fn get_round_score(ally_index: usize, enemy_index: usize) -> usize {
+    let shape_score = ally_index + 1;
+    let diff_index: i8 = i8::try_from(3 + ally_index - enemy_index).unwrap() % 3;
+
+    let outcome_score = match diff_index {
+        0 => 3,
+        1 => 6,
+        _ => 0,
+    };
+
+    shape_score + outcome_score
+}
+
+fn index_of<T: std::cmp::PartialEq + Copy>(array: &[T], value: T) -> usize {
+    array.iter().position(|&r| r == value).unwrap()
+}
@@ -0,0 +1,14 @@
+use common::{solve, Part};
+use day02::Day02;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day02>(EXAMPLE, Part::One).unwrap(), "15");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day02>(EXAMPLE, Part::Two).unwrap(), "12");
+}
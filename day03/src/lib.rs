@@ -0,0 +1,134 @@
+use common::{Generator, ParseError, Random, SolveError, Solver};
+
+const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    type Input = Vec<String>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(line_index, line)| {
+                match line.chars().position(|item| !ALPHABET.contains(item)) {
+                    Some(column_index) => Err(ParseError::at(line_index, column_index, "a letter")),
+                    None if line.len() % 2 != 0 => Err(ParseError::at(
+                        line_index,
+                        line.len(),
+                        "an even number of items",
+                    )),
+                    None => Ok(String::from(line)),
+                }
+            })
+            .collect()
+    }
+
+    fn part_one(rucksacks: &Vec<String>) -> Result<usize, SolveError> {
+        Ok(rucksacks
+            .iter()
+            .map(|rucksack| {
+                let (first_compartment, second_compartment) = rucksack.split_at(rucksack.len() / 2);
+                let duplicate =
+                    find_duplicate(first_compartment, second_compartment, second_compartment);
+                get_letter_score(duplicate)
+            })
+            .sum())
+    }
+
+    fn part_two(rucksacks: &Vec<String>) -> Result<usize, SolveError> {
+        let mut total_priority = 0;
+
+        let mut line_iterator = rucksacks.iter();
+
+        while let (Some(first_elf), Some(second_elf), Some(third_elf)) = (
+            line_iterator.next(),
+            line_iterator.next(),
+            line_iterator.next(),
+        ) {
+            let duplicate = find_duplicate(first_elf, second_elf, third_elf);
+            total_priority += get_letter_score(duplicate);
+        }
+
+        Ok(total_priority)
+    }
+}
+
+// The size is the number of rucksacks, rounded up to whole groups of three.
+// Within a group, every item type but the badge is left out of one rucksack at
+// least, and the compartments of a rucksack share a single item type.
+impl Generator for Day03 {
+    const DEFAULT_SIZE: usize = 300;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        let item_types: Vec<char> = ALPHABET.chars().collect();
+        let mut rucksacks = String::new();
+
+        for _group in 0..size.div_ceil(3) {
+            let badge = *random.choose(&item_types);
+            let left_out_rucksacks: Vec<usize> =
+                item_types.iter().map(|_| random.below(3)).collect();
+
+            for rucksack_index in 0..3 {
+                let mut packable_item_types: Vec<char> = item_types
+                    .iter()
+                    .zip(left_out_rucksacks.iter())
+                    .filter(|&(&item_type, &left_out_rucksack)| {
+                        item_type != badge && left_out_rucksack != rucksack_index
+                    })
+                    .map(|(&item_type, _)| item_type)
+                    .collect();
+                random.shuffle(&mut packable_item_types);
+
+                let shared_item_type = if random.chance(0.1) {
+                    badge
+                } else {
+                    packable_item_types.pop().unwrap()
+                };
+                let (first_item_types, second_item_types) =
+                    packable_item_types.split_at(packable_item_types.len() / 2);
+                let mut compartments = [vec![shared_item_type], vec![shared_item_type]];
+                if shared_item_type != badge {
+                    compartments[random.below(2)].push(badge);
+                }
+
+                let compartment_size = random.between(4, 16) as usize;
+                for (compartment, item_types) in compartments
+                    .iter_mut()
+                    .zip([first_item_types, second_item_types])
+                {
+                    while compartment.len() < compartment_size {
+                        compartment.push(*random.choose(item_types));
+                    }
+                    random.shuffle(compartment);
+                    rucksacks.extend(compartment.iter());
+                }
+                rucksacks.push('\n');
+            }
+        }
+
+        rucksacks
+    }
+}
+
+fn find_duplicate(first_part: &str, second_part: &str, third_part: &str) -> char {
+    for element in first_part.chars() {
+        if second_part.contains(element) && third_part.contains(element) {
+            return element;
+        }
+    }
+
+    '0'
+}
+
+fn get_letter_score(letter: char) -> usize {
+    ALPHABET
+        .chars()
+        .position(|r| r == letter)
+        .map_or(0, |index| index + 1)
+}
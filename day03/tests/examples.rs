@@ -0,0 +1,14 @@
+use common::{solve, Part};
+use day03::Day03;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day03>(EXAMPLE, Part::One).unwrap(), "157");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day03>(EXAMPLE, Part::Two).unwrap(), "70");
+}
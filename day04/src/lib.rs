@@ -0,0 +1,87 @@
+use common::error::{column_of, parse_number};
+use common::{Generator, ParseError, Random, SolveError, Solver};
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Input = Vec<(u32, u32, u32, u32)>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<(u32, u32, u32, u32)>, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(line_index, line_value)| parse_line(line_index, line_value))
+            .collect()
+    }
+
+    fn part_one(assignments: &Vec<(u32, u32, u32, u32)>) -> Result<usize, SolveError> {
+        Ok(assignments
+            .iter()
+            .filter(|&&(start1, end1, start2, end2)| check_inclusion(start1, end1, start2, end2))
+            .count())
+    }
+
+    fn part_two(assignments: &Vec<(u32, u32, u32, u32)>) -> Result<usize, SolveError> {
+        Ok(assignments
+            .iter()
+            .filter(|&&(start1, end1, start2, end2)| check_conflicts(start1, end1, start2, end2))
+            .count())
+    }
+}
+
+// The size is the number of pairs of elves.
+impl Generator for Day04 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        let mut generate_range = || {
+            let start = random.between(1, 99);
+            (start, random.between(start, 99))
+        };
+
+        (0..size)
+            .map(|_| {
+                let (start1, end1) = generate_range();
+                let (start2, end2) = generate_range();
+                format!("{start1}-{end1},{start2}-{end2}\n")
+            })
+            .collect()
+    }
+}
+
+fn parse_line(line_index: usize, line_value: &str) -> Result<(u32, u32, u32, u32), ParseError> {
+    let (range1, range2) = line_value
+        .split_once(',')
+        .ok_or_else(|| ParseError::at(line_index, line_value.len(), "`,`"))?;
+    let (start1, end1) = parse_range(line_index, line_value, range1)?;
+    let (start2, end2) = parse_range(line_index, line_value, range2)?;
+    Ok((start1, end1, start2, end2))
+}
+
+fn parse_range(line_index: usize, line_value: &str, range: &str) -> Result<(u32, u32), ParseError> {
+    let (start, end) = range.split_once('-').ok_or_else(|| {
+        ParseError::at(
+            line_index,
+            column_of(line_value, range),
+            "a range like `2-4`",
+        )
+    })?;
+    let start = parse_number::<u32>(line_index, line_value, start)?;
+    let end = parse_number::<u32>(line_index, line_value, end)?;
+    Ok((start, end))
+}
+
+fn check_inclusion(start1: u32, end1: u32, start2: u32, end2: u32) -> bool {
+    (start1 <= start2 && end2 <= end1) || (start2 <= start1 && end1 <= end2)
+}
+
+fn check_conflicts(start1: u32, end1: u32, start2: u32, end2: u32) -> bool {
+    let max_start = std::cmp::max(start1, start2);
+    let min_end = std::cmp::min(end1, end2);
+
+    max_start <= min_end
+}
@@ -0,0 +1,14 @@
+use common::{solve, Part};
+use day04::Day04;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day04>(EXAMPLE, Part::One).unwrap(), "2");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day04>(EXAMPLE, Part::Two).unwrap(), "4");
+}
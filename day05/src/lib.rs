@@ -0,0 +1,218 @@
+use common::error::parse_number;
+use common::{Generator, ParseError, Random, SolveError, Solver};
+use regex::Regex;
+
+#[derive(Debug)]
+pub struct Move {
+    move_count: usize,
+    crate_origin_index: usize,
+    crate_destination_index: usize,
+}
+
+#[derive(Debug)]
+pub struct Supplies {
+    crate_stacks: Vec<Vec<char>>,
+    moves: Vec<Move>,
+}
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    type Input = Supplies;
+    type AnswerOne = String;
+    type AnswerTwo = String;
+
+    fn parse(input: &str) -> Result<Supplies, ParseError> {
+        let mut is_parsing_crates: bool = true;
+        let mut raw_crates_lines: Vec<&str> = Vec::new();
+        let mut crate_stacks: Vec<Vec<char>> = Vec::new();
+        let mut crate_stack_heights: Vec<usize> = Vec::new();
+        let mut moves: Vec<Move> = Vec::new();
+        let move_regex = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+
+        for (line_index, line_value) in input.lines().enumerate() {
+            if is_parsing_crates {
+                if !line_value.is_empty() {
+                    raw_crates_lines.push(line_value);
+                } else {
+                    let Some(stack_numbers_line) = raw_crates_lines.last() else {
+                        return Err(ParseError::at(line_index, 0, "a drawing of the crates"));
+                    };
+                    let crates_count = (stack_numbers_line.len() + 1) / 4;
+                    for _crate_index in 0..crates_count {
+                        let crate_stack: Vec<char> = Vec::new();
+                        crate_stacks.push(crate_stack);
+                    }
+
+                    raw_crates_lines.reverse();
+                    for crate_line in raw_crates_lines[1..].iter() {
+                        for (index, crate_stack) in crate_stacks.iter_mut().enumerate() {
+                            let crate_name = crate_line.chars().nth(4 * index + 1).unwrap_or(' ');
+                            if crate_name != ' ' {
+                                crate_stack.push(crate_name);
+                            }
+                        }
+                    }
+
+                    crate_stack_heights = crate_stacks.iter().map(Vec::len).collect();
+                    is_parsing_crates = false;
+                }
+            } else {
+                let captures = move_regex.captures(line_value).ok_or_else(|| {
+                    ParseError::at(line_index, 0, "`move <count> from <stack> to <stack>`")
+                })?;
+                let parse_capture =
+                    |group: usize| parse_number::<usize>(line_index, line_value, &captures[group]);
+                let parse_stack = |group: usize| {
+                    let stack_number = parse_capture(group)?;
+                    if stack_number == 0 || stack_number > crate_stacks.len() {
+                        let column_index = captures.get(group).unwrap().start();
+                        return Err(ParseError::at(
+                            line_index,
+                            column_index,
+                            format!("a stack between 1 and {}", crate_stacks.len()),
+                        ));
+                    }
+                    Ok(stack_number - 1)
+                };
+
+                let crate_move = Move {
+                    move_count: parse_capture(1)?,
+                    crate_origin_index: parse_stack(2)?,
+                    crate_destination_index: parse_stack(3)?,
+                };
+
+                if crate_stack_heights[crate_move.crate_origin_index] < crate_move.move_count {
+                    return Err(ParseError::at(
+                        line_index,
+                        captures.get(1).unwrap().start(),
+                        format!(
+                            "at most {} crates to move",
+                            crate_stack_heights[crate_move.crate_origin_index]
+                        ),
+                    ));
+                }
+                crate_stack_heights[crate_move.crate_origin_index] -= crate_move.move_count;
+                crate_stack_heights[crate_move.crate_destination_index] += crate_move.move_count;
+
+                moves.push(crate_move);
+            }
+        }
+
+        if is_parsing_crates {
+            return Err(ParseError::at(
+                raw_crates_lines.len(),
+                0,
+                "an empty line after the drawing of the crates",
+            ));
+        }
+
+        Ok(Supplies {
+            crate_stacks,
+            moves,
+        })
+    }
+
+    fn part_one(supplies: &Supplies) -> Result<String, SolveError> {
+        let mut crate_stacks = supplies.crate_stacks.clone();
+
+        for crate_move in supplies.moves.iter() {
+            for _index in 0..crate_move.move_count {
+                let crate_name = crate_stacks[crate_move.crate_origin_index].pop().unwrap();
+                crate_stacks[crate_move.crate_destination_index].push(crate_name);
+            }
+        }
+
+        Ok(get_top_crates(&crate_stacks))
+    }
+
+    fn part_two(supplies: &Supplies) -> Result<String, SolveError> {
+        let mut crate_stacks = supplies.crate_stacks.clone();
+
+        for crate_move in supplies.moves.iter() {
+            let mut temporary_stack: Vec<char> = Vec::new();
+            for _index in 0..crate_move.move_count {
+                temporary_stack.push(crate_stacks[crate_move.crate_origin_index].pop().unwrap());
+            }
+
+            for _index in 0..crate_move.move_count {
+                crate_stacks[crate_move.crate_destination_index]
+                    .push(temporary_stack.pop().unwrap());
+            }
+        }
+
+        Ok(get_top_crates(&crate_stacks))
+    }
+}
+
+// The size is the number of moves, between nine stacks of crates. Moves never
+// take more crates than a stack holds.
+impl Generator for Day05 {
+    const DEFAULT_SIZE: usize = 500;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        const STACK_COUNT: usize = 9;
+
+        let crate_stacks: Vec<Vec<char>> = (0..STACK_COUNT)
+            .map(|_| {
+                (0..random.between(1, 8))
+                    .map(|_| char::from(b'A' + random.below(26) as u8))
+                    .collect()
+            })
+            .collect();
+        let max_height = crate_stacks.iter().map(Vec::len).max().unwrap();
+
+        let mut lines: Vec<String> = (0..max_height)
+            .rev()
+            .map(|level| {
+                crate_stacks
+                    .iter()
+                    .map(|crate_stack| match crate_stack.get(level) {
+                        Some(crate_name) => format!("[{crate_name}]"),
+                        None => String::from("   "),
+                    })
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            })
+            .collect();
+        lines.push(
+            (1..=STACK_COUNT)
+                .map(|stack_number| format!(" {stack_number} "))
+                .collect::<Vec<String>>()
+                .join(" "),
+        );
+        lines.push(String::new());
+
+        let mut stack_heights: Vec<usize> = crate_stacks.iter().map(Vec::len).collect();
+        for _move in 0..size {
+            let origin_index = loop {
+                let origin_index = random.below(STACK_COUNT);
+                if stack_heights[origin_index] > 0 {
+                    break origin_index;
+                }
+            };
+            let destination_index =
+                (origin_index + 1 + random.below(STACK_COUNT - 1)) % STACK_COUNT;
+            let move_count = random.between(1, stack_heights[origin_index].min(10) as i64) as usize;
+
+            stack_heights[origin_index] -= move_count;
+            stack_heights[destination_index] += move_count;
+            lines.push(format!(
+                "move {move_count} from {} to {}",
+                origin_index + 1,
+                destination_index + 1
+            ));
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+fn get_top_crates(crate_stacks: &[Vec<char>]) -> String {
+    crate_stacks
+        .iter()
+        .filter_map(|crate_stack| crate_stack.last())
+        .collect()
+}
@@ -0,0 +1,14 @@
+use common::{solve, Part};
+use day05::Day05;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day05>(EXAMPLE, Part::One).unwrap(), "CMZ");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day05>(EXAMPLE, Part::Two).unwrap(), "MCD");
+}
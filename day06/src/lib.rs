@@ -0,0 +1,178 @@
+use common::stream::{parse_symbols, read_symbols, StreamError};
+use common::{Generator, Oracle, ParseError, Random, SolveError, Solver, Stream};
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+const PACKET_MARKER_SIZE: usize = 4;
+const MESSAGE_MARKER_SIZE: usize = 14;
+
+mod naive;
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Input = String;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        parse_symbols(input, "a lowercase letter", |letter| {
+            letter.is_ascii_lowercase()
+        })?;
+
+        Ok(String::from(input.trim_end()))
+    }
+
+    fn part_one(data: &String) -> Result<usize, SolveError> {
+        find_marker(data, PACKET_MARKER_SIZE).ok_or_else(get_no_packet_marker_error)
+    }
+
+    fn part_two(data: &String) -> Result<usize, SolveError> {
+        find_marker(data, MESSAGE_MARKER_SIZE).ok_or_else(get_no_message_marker_error)
+    }
+}
+
+// The size is the length of the datastream, at least 32. Both markers are
+// planted, the letters before each one repeating the last ones so that they
+// cannot make it sooner.
+impl Generator for Day06 {
+    const DEFAULT_SIZE: usize = 4096;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        let size = size.max(32);
+        let packet_marker_start = size / 8 + random.below(size / 8);
+        let message_marker_start = size / 2 + random.below(size / 2 - MESSAGE_MARKER_SIZE);
+
+        let mut data: Vec<u8> = Vec::with_capacity(size);
+        push_repeats(random, &mut data, packet_marker_start, PACKET_MARKER_SIZE);
+        data.extend(get_marker(random, PACKET_MARKER_SIZE));
+        push_repeats(random, &mut data, message_marker_start, MESSAGE_MARKER_SIZE);
+        data.extend(get_marker(random, MESSAGE_MARKER_SIZE));
+        while data.len() < size {
+            data.push(b'a' + random.below(26) as u8);
+        }
+
+        String::from_utf8(data).unwrap() + "\n"
+    }
+}
+
+// The first solution, comparing every pair of letters of every window.
+impl Oracle for Day06 {
+    fn naive_part_one(data: &String) -> Result<usize, SolveError> {
+        naive::find_marker(data, PACKET_MARKER_SIZE).ok_or_else(get_no_packet_marker_error)
+    }
+
+    fn naive_part_two(data: &String) -> Result<usize, SolveError> {
+        naive::find_marker(data, MESSAGE_MARKER_SIZE).ok_or_else(get_no_message_marker_error)
+    }
+}
+
+// Both markers are looked for in a single pass, the datastream being read a
+// letter at a time.
+impl Stream for Day06 {
+    fn stream(reader: impl BufRead) -> Result<(usize, usize), StreamError> {
+        let mut packet_window = MarkerWindow::new(PACKET_MARKER_SIZE);
+        let mut message_window = MarkerWindow::new(MESSAGE_MARKER_SIZE);
+        let mut packet_marker = None;
+        let mut message_marker = None;
+
+        let mut letter_count = 0;
+        read_symbols(reader, "a lowercase letter", |letter| {
+            if !letter.is_ascii_lowercase() {
+                return false;
+            }
+            letter_count += 1;
+            if packet_marker.is_none() && packet_window.push(letter) {
+                packet_marker = Some(letter_count);
+            }
+            if message_marker.is_none() && message_window.push(letter) {
+                message_marker = Some(letter_count);
+            }
+            true
+        })?;
+
+        Ok((
+            packet_marker.ok_or_else(get_no_packet_marker_error)?,
+            message_marker.ok_or_else(get_no_message_marker_error)?,
+        ))
+    }
+}
+
+// A window sliding over the datastream, counting the letters in it and how
+// many of them are there more than once.
+struct MarkerWindow {
+    letters: VecDeque<u8>,
+    marker_size: usize,
+    letter_counts: [usize; 26],
+    repeated_letter_count: usize,
+}
+
+impl MarkerWindow {
+    fn new(marker_size: usize) -> MarkerWindow {
+        MarkerWindow {
+            letters: VecDeque::with_capacity(marker_size + 1),
+            marker_size,
+            letter_counts: [0; 26],
+            repeated_letter_count: 0,
+        }
+    }
+
+    // Slides the window over the next letter, and tells whether the window
+    // then holds a marker.
+    fn push(&mut self, letter: u8) -> bool {
+        let letter_count = &mut self.letter_counts[(letter - b'a') as usize];
+        *letter_count += 1;
+        if *letter_count == 2 {
+            self.repeated_letter_count += 1;
+        }
+        self.letters.push_back(letter);
+
+        if self.letters.len() > self.marker_size {
+            let first_letter = self.letters.pop_front().unwrap();
+            let letter_count = &mut self.letter_counts[(first_letter - b'a') as usize];
+            *letter_count -= 1;
+            if *letter_count == 1 {
+                self.repeated_letter_count -= 1;
+            }
+        }
+
+        self.letters.len() == self.marker_size && self.repeated_letter_count == 0
+    }
+}
+
+// Nothing when the datastream ends before any marker.
+fn find_marker(data: &str, marker_size: usize) -> Option<usize> {
+    let mut window = MarkerWindow::new(marker_size);
+    data.bytes()
+        .position(|letter| window.push(letter))
+        .map(|index| index + 1)
+}
+
+fn get_no_packet_marker_error() -> SolveError {
+    SolveError::new("no start-of-packet marker in the datastream")
+}
+
+fn get_no_message_marker_error() -> SolveError {
+    SolveError::new("no start-of-message marker in the datastream")
+}
+
+fn get_marker(random: &mut Random, marker_size: usize) -> Vec<u8> {
+    let mut letters: Vec<u8> = (b'a'..=b'z').collect();
+    random.shuffle(&mut letters);
+    letters.truncate(marker_size);
+    letters
+}
+
+// Pushes letters up to `end`, each one repeating one of the last ones so that
+// no marker of the size is made.
+fn push_repeats(random: &mut Random, data: &mut Vec<u8>, end: usize, marker_size: usize) {
+    while data.len() < end {
+        let letter = match data.len() {
+            length if length >= marker_size - 1 => data[length - 1 - random.below(marker_size - 1)],
+            _ => b'a' + random.below(26) as u8,
+        };
+        data.push(letter);
+    }
+}
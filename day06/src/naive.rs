@@ -0,0 +1,25 @@
+pub fn find_marker(data: &str, marker_size: usize) -> Option<usize> {
+    for i in marker_size..(data.len() + 1) {
+        if check_duplicate(&data[i - marker_size..i]) {
+            return Some(i);
+        }
+    }
+
+    None
+}
+
+fn check_duplicate(string: &str) -> bool {
+    for i in 0..string.len() {
+        let current_letter = at(string, i);
+        for j in i + 1..string.len() {
+            if current_letter == at(string, j) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn at(string: &str, index: usize) -> char {
+    string.chars().nth(index).unwrap()
+}
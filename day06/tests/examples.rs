@@ -0,0 +1,30 @@
+use common::{solve, Error, Part, SolveError};
+use day06::Day06;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day06>(EXAMPLE, Part::One).unwrap(), "6");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day06>(EXAMPLE, Part::Two).unwrap(), "23");
+}
+
+#[test]
+fn no_marker() {
+    assert_eq!(
+        solve::<Day06>("abc\n", Part::One),
+        Err(Error::Solve(SolveError::new(
+            "no start-of-packet marker in the datastream"
+        )))
+    );
+    assert_eq!(
+        solve::<Day06>("abcd\n", Part::Two),
+        Err(Error::Solve(SolveError::new(
+            "no start-of-message marker in the datastream"
+        )))
+    );
+}
@@ -0,0 +1,7 @@
+use common::oracle::compare;
+use day06::Day06;
+
+#[test]
+fn optimised_solutions_agree_with_the_naive_ones() {
+    assert_eq!(compare::<Day06>(0, 1000, None), Ok(()));
+}
@@ -0,0 +1,31 @@
+use common::generator;
+use common::test_support::assert_stream_matches;
+use common::Solver;
+use day06::Day06;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn streaming_the_example_gives_the_same_answers() {
+    assert_stream_matches::<Day06>(EXAMPLE);
+}
+
+#[test]
+fn streaming_generated_inputs_gives_the_same_answers() {
+    for seed in 0..50 {
+        assert_stream_matches::<Day06>(&generator::generate::<Day06>(seed, Some(200)));
+    }
+}
+
+#[test]
+fn streaming_reports_the_same_parse_error() {
+    let input = "abcdefg\nhij\n";
+    assert!(Day06::parse(input).is_err());
+    assert_stream_matches::<Day06>(input);
+}
+
+#[test]
+fn streaming_reports_the_same_missing_marker() {
+    assert_stream_matches::<Day06>("abc\n");
+    assert_stream_matches::<Day06>("abcd\n");
+}
@@ -0,0 +1,207 @@
+use common::error::parse_number;
+use common::{Generator, Oracle, ParseError, Random, SolveError, Solver};
+use regex::Regex;
+use std::collections::HashMap;
+
+const TOTAL_SPACE: usize = 70000000;
+const REQUIRED_SPACE: usize = 30000000;
+const SMALL_DIRECTORY_SIZE: usize = 100000;
+
+mod naive;
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    type Input = HashMap<String, usize>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<HashMap<String, usize>, ParseError> {
+        parse_directory_sizes(input)
+    }
+
+    fn part_one(directory_size: &HashMap<String, usize>) -> Result<usize, SolveError> {
+        Ok(directory_size
+            .values()
+            .filter(|&&size| size <= SMALL_DIRECTORY_SIZE)
+            .sum())
+    }
+
+    fn part_two(directory_size: &HashMap<String, usize>) -> Result<usize, SolveError> {
+        let used_size = directory_size["/"];
+        let free_space = TOTAL_SPACE.saturating_sub(used_size);
+        let perfect_space_to_free = REQUIRED_SPACE.saturating_sub(free_space);
+
+        directory_size
+            .values()
+            .filter(|&&size| size > perfect_space_to_free)
+            .min()
+            .copied()
+            .ok_or_else(|| SolveError::new("no directory frees enough space"))
+    }
+}
+
+// The size is the number of files, spread over a third as many directories.
+// The files fill between 45 and 65 of the 70 million of the disk, so that a
+// directory must always be deleted.
+impl Generator for Day07 {
+    const DEFAULT_SIZE: usize = 300;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        let directory_count = size / 3 + 1;
+        // The subdirectories of each directory, the first one being the root.
+        let mut subdirectories: Vec<Vec<(String, usize)>> = vec![Vec::new(); directory_count];
+        for directory_index in 1..directory_count {
+            let parent_index = random.below(directory_index);
+            let name = generate_name(random, directory_index);
+            subdirectories[parent_index].push((name, directory_index));
+        }
+
+        let used_space = random.between(45_000_000, 65_000_000) as usize;
+        let weights: Vec<usize> = (0..size)
+            .map(|_| random.between(1, 1000) as usize)
+            .collect();
+        let total_weight: usize = weights.iter().sum();
+        let mut files: Vec<Vec<(String, usize)>> = vec![Vec::new(); directory_count];
+        for (file_index, weight) in weights.into_iter().enumerate() {
+            let name = generate_name(random, file_index) + "." + &generate_name(random, 0)[..3];
+            files[random.below(directory_count)]
+                .push((name, (used_space * weight / total_weight).max(1)));
+        }
+
+        let mut transcript = String::from("$ cd /\n");
+        write_directory(&mut transcript, &subdirectories, &files, 0);
+        transcript
+    }
+}
+
+// The first parsing, building the directory sizes again for every file.
+impl Oracle for Day07 {
+    fn naive_parse(input: &str) -> Result<HashMap<String, usize>, ParseError> {
+        naive::parse_directory_sizes(input)
+    }
+}
+
+fn parse_directory_sizes(input: &str) -> Result<HashMap<String, usize>, ParseError> {
+    let cd_regex = Regex::new(r"^\$ cd ([a-z]+)$").unwrap();
+    let node_regex = Regex::new(r"^(\d+) (.+)$").unwrap();
+    let dir_regex = Regex::new(r"^dir (.+)$").unwrap();
+
+    let mut current_path = String::from("");
+
+    let mut directory_size: HashMap<String, usize> = HashMap::new();
+
+    for (line_index, line) in input.lines().enumerate() {
+        if line == "$ cd /" {
+            current_path = String::from("/");
+            directory_size.entry(String::from("/")).or_insert(0);
+            continue;
+        }
+
+        if current_path.is_empty() {
+            return Err(ParseError::at(line_index, 0, "`$ cd /`"));
+        }
+
+        if line == "$ ls" {
+            continue;
+        }
+
+        if line == "$ cd .." {
+            if current_path == "/" {
+                return Err(ParseError::at(line_index, 0, "a directory to leave"));
+            }
+            current_path.pop();
+            while let Some(char) = current_path.pop() {
+                if char == '/' {
+                    break;
+                }
+            }
+            current_path.push('/');
+            continue;
+        }
+
+        if let Some(cd_captures) = cd_regex.captures(line) {
+            let directory_name = String::from(&cd_captures[1]);
+
+            current_path.push_str(&directory_name);
+            current_path.push('/');
+            continue;
+        }
+
+        if let Some(dir_captures) = dir_regex.captures(line) {
+            let directory_name = String::from(&dir_captures[1]);
+
+            let directory_path = format!("{current_path}{directory_name}/");
+            directory_size.insert(directory_path, 0);
+            continue;
+        }
+
+        if let Some(node_captures) = node_regex.captures(line) {
+            let size = parse_number::<usize>(line_index, line, &node_captures[1])?;
+
+            // The file is in every directory up the current path.
+            for (index, _) in current_path.match_indices('/') {
+                if let Some(directory_size) = directory_size.get_mut(&current_path[..=index]) {
+                    *directory_size = directory_size.checked_add(size).ok_or_else(|| {
+                        ParseError::at(
+                            line_index,
+                            0,
+                            format!("a size keeping the directories up to {}", usize::MAX),
+                        )
+                    })?;
+                }
+            }
+            continue;
+        }
+
+        return Err(ParseError::at(
+            line_index,
+            0,
+            "`$ cd <directory>`, `$ ls`, `dir <name>` or `<size> <name>`",
+        ));
+    }
+
+    if directory_size.is_empty() {
+        return Err(ParseError::at(0, 0, "`$ cd /`"));
+    }
+
+    Ok(directory_size)
+}
+
+// A name of a few letters, made unique by the index it ends with, spelled in
+// letters too.
+fn generate_name(random: &mut Random, index: usize) -> String {
+    let mut name: String = (0..random.between(3, 6))
+        .map(|_| char::from(b'a' + random.below(26) as u8))
+        .collect();
+    let mut rest = index;
+    while rest > 0 {
+        name.push(char::from(b'a' + (rest % 26) as u8));
+        rest /= 26;
+    }
+    name
+}
+
+// Lists a directory, then walks into each of its subdirectories and back.
+fn write_directory(
+    transcript: &mut String,
+    subdirectories: &[Vec<(String, usize)>],
+    files: &[Vec<(String, usize)>],
+    directory_index: usize,
+) {
+    transcript.push_str("$ ls\n");
+    for (name, _) in subdirectories[directory_index].iter() {
+        transcript.push_str(&format!("dir {name}\n"));
+    }
+    for (name, size) in files[directory_index].iter() {
+        transcript.push_str(&format!("{size} {name}\n"));
+    }
+
+    for (name, subdirectory_index) in subdirectories[directory_index].iter() {
+        transcript.push_str(&format!("$ cd {name}\n"));
+        write_directory(transcript, subdirectories, files, *subdirectory_index);
+        transcript.push_str("$ cd ..\n");
+    }
+}
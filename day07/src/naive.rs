@@ -0,0 +1,87 @@
+use common::error::parse_number;
+use common::ParseError;
+use regex::Regex;
+use std::collections::HashMap;
+
+pub fn parse_directory_sizes(input: &str) -> Result<HashMap<String, usize>, ParseError> {
+    let cd_regex = Regex::new(r"^\$ cd ([a-z]+)$").unwrap();
+    let node_regex = Regex::new(r"^(\d+) (.+)$").unwrap();
+    let dir_regex = Regex::new(r"^dir (.+)$").unwrap();
+
+    let mut current_path = String::from("");
+
+    let mut directory_size: HashMap<String, usize> = HashMap::new();
+
+    for (line_index, line) in input.lines().enumerate() {
+        if line == "$ cd /" {
+            current_path = String::from("/");
+            directory_size.entry(String::from("/")).or_insert(0);
+            continue;
+        }
+
+        if current_path.is_empty() {
+            return Err(ParseError::at(line_index, 0, "`$ cd /`"));
+        }
+
+        if line == "$ ls" {
+            continue;
+        }
+
+        if line == "$ cd .." {
+            if current_path == "/" {
+                return Err(ParseError::at(line_index, 0, "a directory to leave"));
+            }
+            current_path.pop();
+            while let Some(char) = current_path.pop() {
+                if char == '/' {
+                    break;
+                }
+            }
+            current_path.push('/');
+            continue;
+        }
+
+        if let Some(cd_captures) = cd_regex.captures(line) {
+            let directory_name = String::from(&cd_captures[1]);
+
+            current_path.push_str(&directory_name);
+            current_path.push('/');
+            continue;
+        }
+
+        if let Some(dir_captures) = dir_regex.captures(line) {
+            let directory_name = String::from(&dir_captures[1]);
+
+            let directory_path = format!("{current_path}{directory_name}/");
+            directory_size.insert(directory_path, 0);
+            continue;
+        }
+
+        if let Some(node_captures) = node_regex.captures(line) {
+            let size = parse_number::<usize>(line_index, line, &node_captures[1])?;
+
+            let mut new_directory_path = HashMap::new();
+            for (directory_path, directory_size) in directory_size {
+                let mut new_size = directory_size;
+                if current_path.starts_with(&directory_path) {
+                    new_size += size;
+                }
+                new_directory_path.insert(directory_path, new_size);
+            }
+            directory_size = new_directory_path;
+            continue;
+        }
+
+        return Err(ParseError::at(
+            line_index,
+            0,
+            "`$ cd <directory>`, `$ ls`, `dir <name>` or `<size> <name>`",
+        ));
+    }
+
+    if directory_size.is_empty() {
+        return Err(ParseError::at(0, 0, "`$ cd /`"));
+    }
+
+    Ok(directory_size)
+}
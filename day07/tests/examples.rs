@@ -0,0 +1,43 @@
+use common::{solve, Error, ParseError, Part, SolveError};
+use day07::Day07;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+const EXAMPLE2: &str = include_str!("../example2.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day07>(EXAMPLE, Part::One).unwrap(), "95437");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day07>(EXAMPLE, Part::Two).unwrap(), "24933642");
+}
+
+#[test]
+fn part_one_second_example() {
+    assert_eq!(solve::<Day07>(EXAMPLE2, Part::One).unwrap(), "33");
+}
+
+#[test]
+fn part_two_second_example() {
+    assert_eq!(solve::<Day07>(EXAMPLE2, Part::Two).unwrap(), "1");
+}
+
+#[test]
+fn leaving_the_root_is_invalid() {
+    assert_eq!(
+        solve::<Day07>("$ cd /\n$ cd ..\n", Part::One),
+        Err(Error::Parse(ParseError::at(1, 0, "a directory to leave")))
+    );
+}
+
+#[test]
+fn no_directory_large_enough_gives_no_answer() {
+    assert_eq!(
+        solve::<Day07>("$ cd /\n$ ls\n", Part::Two),
+        Err(Error::Solve(SolveError::new(
+            "no directory frees enough space"
+        )))
+    );
+}
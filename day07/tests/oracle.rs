@@ -0,0 +1,7 @@
+use common::oracle::compare;
+use day07::Day07;
+
+#[test]
+fn optimised_solutions_agree_with_the_naive_ones() {
+    assert_eq!(compare::<Day07>(0, 200, None), Ok(()));
+}
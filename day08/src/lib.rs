@@ -0,0 +1,104 @@
+use common::{Generator, Grid, Image, ParseError, Point, Random, Render, Rgb, SolveError, Solver};
+
+const TREE_SCALE: usize = 8;
+const SHORTEST_TREE_COLOR: Rgb = Rgb(40, 30, 20);
+const TALLEST_TREE_COLOR: Rgb = Rgb(120, 220, 80);
+const TREE_HOUSE_COLOR: Rgb = Rgb(230, 40, 40);
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    type Input = Grid<u32>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, "a digit", |char| char.to_digit(10))
+    }
+
+    fn part_one(forest: &Grid<u32>) -> Result<usize, SolveError> {
+        Ok(forest
+            .points()
+            .filter(|&tree| is_visible(forest, tree))
+            .count())
+    }
+
+    fn part_two(forest: &Grid<u32>) -> Result<usize, SolveError> {
+        Ok(forest
+            .points()
+            .map(|tree| get_scenic_score(forest, tree))
+            .max()
+            .unwrap())
+    }
+}
+
+// The size is the side of the forest.
+impl Generator for Day08 {
+    const DEFAULT_SIZE: usize = 99;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                (0..size.max(1))
+                    .map(|_| char::from(b'0' + random.below(10) as u8))
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect()
+    }
+}
+
+// A heatmap of the heights of the trees, with the tree house on the tree having
+// the best scenic score.
+impl Render for Day08 {
+    fn render(forest: &Grid<u32>) -> Vec<Image> {
+        let mut image = Image::from_grid(forest, TREE_SCALE, |&height| {
+            SHORTEST_TREE_COLOR.blend(TALLEST_TREE_COLOR, height as f64 / 9.)
+        });
+        let tree_house = forest
+            .points()
+            .max_by_key(|&tree| get_scenic_score(forest, tree))
+            .unwrap();
+        image.fill_cell(tree_house, TREE_SCALE, TREE_HOUSE_COLOR);
+
+        vec![image]
+    }
+}
+
+fn is_visible(forest: &Grid<u32>, tree: Point<usize>) -> bool {
+    let (length, width) = (tree.y, tree.x);
+    let height = forest[tree];
+
+    forest.line(length)[..width]
+        .iter()
+        .all(|&tree| tree < height)
+        || forest.line(length)[width + 1..]
+            .iter()
+            .all(|&tree| tree < height)
+        || forest.column(width).take(length).all(|&tree| tree < height)
+        || forest
+            .column(width)
+            .skip(length + 1)
+            .all(|&tree| tree < height)
+}
+
+fn get_scenic_score(forest: &Grid<u32>, tree: Point<usize>) -> usize {
+    let (length, width) = (tree.y, tree.x);
+    let height = forest[tree];
+
+    get_view(forest.line(length)[..width].iter().rev(), height)
+        * get_view(forest.line(length)[width + 1..].iter(), height)
+        * get_view(forest.column(width).take(length).rev(), height)
+        * get_view(forest.column(width).skip(length + 1), height)
+}
+
+// Counts the trees seen from a tree of the given height, looking away from it
+// until the first tree at least as tall or the edge of the forest.
+fn get_view<'a>(mut trees: impl ExactSizeIterator<Item = &'a u32>, height: u32) -> usize {
+    let tree_count = trees.len();
+    trees
+        .position(|&tree| tree >= height)
+        .map_or(tree_count, |index| index + 1)
+}
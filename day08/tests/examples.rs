@@ -0,0 +1,14 @@
+use common::{solve, Part};
+use day08::Day08;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day08>(EXAMPLE, Part::One).unwrap(), "21");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day08>(EXAMPLE, Part::Two).unwrap(), "8");
+}
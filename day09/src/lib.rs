@@ -0,0 +1,165 @@
+use common::error::parse_number;
+use common::{
+    Animation, Direction, Generator, ParseError, Point, Random, Recording, SolveError, Solver,
+};
+use std::cmp::max;
+use std::cmp::min;
+use std::collections::HashSet;
+
+const SHORT_ROPE_LENGTH: usize = 2;
+const LONG_ROPE_LENGTH: usize = 10;
+// Knots drawn around the head of the rope, on every side.
+const VIEW_MARGIN: i64 = 10;
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    type Input = Vec<(Direction, u64)>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<(Direction, u64)>, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(line_index, line)| {
+                let (direction, steps) = line
+                    .split_once(' ')
+                    .ok_or_else(|| ParseError::at(line_index, 0, "a motion like `R 4`"))?;
+                let direction = match direction {
+                    "U" => Some(Direction::Up),
+                    "D" => Some(Direction::Down),
+                    "L" => Some(Direction::Left),
+                    "R" => Some(Direction::Right),
+                    _ => None,
+                }
+                .ok_or_else(|| ParseError::at(line_index, 0, "`U`, `D`, `L` or `R`"))?;
+                let steps = parse_number::<u64>(line_index, line, steps)?;
+
+                Ok((direction, steps))
+            })
+            .collect()
+    }
+
+    fn part_one(motions: &Vec<(Direction, u64)>) -> Result<usize, SolveError> {
+        Ok(count_tail_positions(motions, SHORT_ROPE_LENGTH))
+    }
+
+    fn part_two(motions: &Vec<(Direction, u64)>) -> Result<usize, SolveError> {
+        Ok(count_tail_positions(motions, LONG_ROPE_LENGTH))
+    }
+}
+
+// The size is the number of motions.
+impl Generator for Day09 {
+    const DEFAULT_SIZE: usize = 2000;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let direction = random.choose(&["U", "D", "L", "R"]);
+                format!("{direction} {}\n", random.between(1, 19))
+            })
+            .collect()
+    }
+}
+
+impl Animation for Day09 {
+    fn animate(motions: &Vec<(Direction, u64)>, recording: &mut Recording) {
+        let mut rope = vec![Point::ORIGIN; LONG_ROPE_LENGTH];
+        recording.record(|| render_rope(&rope));
+
+        for (direction, steps) in motions.iter() {
+            for _ in 0..*steps {
+                if recording.is_full() {
+                    return;
+                }
+                move_rope(&mut rope, direction);
+                recording.record(|| render_rope(&rope));
+            }
+        }
+    }
+}
+
+fn count_tail_positions(motions: &[(Direction, u64)], rope_length: usize) -> usize {
+    let mut rope = vec![Point::ORIGIN; rope_length];
+
+    let mut tail_positions_set: HashSet<Point<i64>> = HashSet::new();
+    tail_positions_set.insert(rope[rope_length - 1]);
+
+    for (direction, steps) in motions.iter() {
+        for _ in 0..*steps {
+            move_rope(&mut rope, direction);
+            tail_positions_set.insert(rope[rope_length - 1]);
+        }
+    }
+
+    tail_positions_set.len()
+}
+
+fn move_rope(rope: &mut [Point<i64>], direction: &Direction) {
+    let mut new_head_position = rope[0].step(*direction);
+    rope[0] = new_head_position;
+
+    for knot in rope.iter_mut().skip(1) {
+        let new_tail_position = move_tail_rope(&new_head_position, *knot);
+        *knot = new_tail_position;
+        new_head_position = new_tail_position;
+    }
+}
+
+fn move_tail_rope(new_head_position: &Point<i64>, tail_position: Point<i64>) -> Point<i64> {
+    let distance = new_head_position.chebyshev_distance(tail_position);
+
+    if distance <= 1 {
+        return tail_position;
+    }
+
+    tail_position + (*new_head_position - tail_position).signum()
+}
+
+// Draws the rope around its head, the start being `s`.
+fn render_rope(rope: &[Point<i64>]) -> String {
+    let head = rope[0];
+    let (min_x, max_x, min_y, max_y) = rope.iter().fold(
+        (
+            head.x - VIEW_MARGIN,
+            head.x + VIEW_MARGIN,
+            head.y - VIEW_MARGIN,
+            head.y + VIEW_MARGIN,
+        ),
+        |(min_x, max_x, min_y, max_y), knot| {
+            (
+                min(min_x, knot.x),
+                max(max_x, knot.x),
+                min(min_y, knot.y),
+                max(max_y, knot.y),
+            )
+        },
+    );
+
+    let mut grid: Vec<Vec<char>> = (min_y..max_y + 1)
+        .map(|y| {
+            (min_x..max_x + 1)
+                .map(|x| if x == 0 && y == 0 { 's' } else { '.' })
+                .collect()
+        })
+        .collect();
+
+    for (index, knot) in rope.iter().enumerate().rev() {
+        let x: usize = usize::try_from(knot.x - min_x).unwrap();
+        let y: usize = usize::try_from(knot.y - min_y).unwrap();
+
+        grid[y][x] = match index {
+            0 => 'H',
+            _ => char::from_digit(index as u32, 10).unwrap(),
+        };
+    }
+
+    grid.into_iter()
+        .map(|line| line.into_iter().collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
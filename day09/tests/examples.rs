@@ -0,0 +1,25 @@
+use common::{solve, Part};
+use day09::Day09;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+const EXAMPLE2: &str = include_str!("../example2.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day09>(EXAMPLE, Part::One).unwrap(), "13");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day09>(EXAMPLE, Part::Two).unwrap(), "1");
+}
+
+#[test]
+fn part_one_second_example() {
+    assert_eq!(solve::<Day09>(EXAMPLE2, Part::One).unwrap(), "88");
+}
+
+#[test]
+fn part_two_second_example() {
+    assert_eq!(solve::<Day09>(EXAMPLE2, Part::Two).unwrap(), "36");
+}
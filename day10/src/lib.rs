@@ -0,0 +1,133 @@
+use common::error::{column_of, parse_number};
+use common::{Generator, Image, ParseError, Point, Random, Render, Rgb, SolveError, Solver};
+
+const SCREEN_WIDTH: usize = 40;
+const SCREEN_HEIGHT: usize = 6;
+const SIGNAL_CYCLES: [usize; 6] = [20, 60, 100, 140, 180, 220];
+const PIXEL_SCALE: usize = 10;
+const LIT_COLOR: Rgb = Rgb(255, 204, 0);
+const DARK_COLOR: Rgb = Rgb(15, 15, 35);
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input = Vec<i32>;
+    type AnswerOne = i32;
+    type AnswerTwo = String;
+
+    fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+        let mut x_history: Vec<i32> = vec![1];
+
+        for (line_index, line_value) in input.lines().enumerate() {
+            if line_value == "noop" {
+                x_history.push(*x_history.last().unwrap());
+            } else if let Some(raw_added_value) = line_value.strip_prefix("addx ") {
+                let added_value = parse_number::<i32>(line_index, line_value, raw_added_value)?;
+                let x = *x_history.last().unwrap();
+                let next_x = x.checked_add(added_value).ok_or_else(|| {
+                    ParseError::at(
+                        line_index,
+                        column_of(line_value, raw_added_value),
+                        format!("a value keeping X between {} and {}", i32::MIN, i32::MAX),
+                    )
+                })?;
+                x_history.push(x);
+                x_history.push(next_x);
+            } else {
+                return Err(ParseError::at(line_index, 0, "`noop` or `addx <value>`"));
+            }
+        }
+
+        if x_history.len() < SCREEN_WIDTH * SCREEN_HEIGHT {
+            return Err(ParseError::at(
+                input.lines().count(),
+                0,
+                format!(
+                    "instructions lasting {} cycles",
+                    SCREEN_WIDTH * SCREEN_HEIGHT
+                ),
+            ));
+        }
+
+        Ok(x_history)
+    }
+
+    fn part_one(x_history: &Vec<i32>) -> Result<i32, SolveError> {
+        Ok(SIGNAL_CYCLES
+            .iter()
+            .map(|&cycle| i32::try_from(cycle).unwrap() * x_history[cycle - 1])
+            .sum())
+    }
+
+    fn part_two(x_history: &Vec<i32>) -> Result<String, SolveError> {
+        let screen = get_lit_pixels(x_history)
+            .into_iter()
+            .map(|is_lit| if is_lit { '#' } else { '.' })
+            .collect::<String>();
+
+        Ok((0..SCREEN_HEIGHT)
+            .map(|line| &screen[line * SCREEN_WIDTH..(line + 1) * SCREEN_WIDTH])
+            .collect::<Vec<&str>>()
+            .join("\n"))
+    }
+}
+
+// The size is the number of instructions, more being added until the screen is
+// drawn entirely. The register stays around the screen, as in the real inputs.
+impl Generator for Day10 {
+    const DEFAULT_SIZE: usize = 140;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        let mut instructions = String::new();
+        let mut instruction_count = 0;
+        let mut cycle_count = 0;
+        let mut x: i64 = 1;
+
+        while instruction_count < size || cycle_count < SCREEN_WIDTH * SCREEN_HEIGHT {
+            if random.chance(0.3) {
+                instructions.push_str("noop\n");
+                cycle_count += 1;
+            } else {
+                let added_value = random.between(-x.min(10) - 5, (40 - x).min(10) + 5);
+                x += added_value;
+                instructions.push_str(&format!("addx {added_value}\n"));
+                cycle_count += 2;
+            }
+            instruction_count += 1;
+        }
+
+        instructions
+    }
+}
+
+impl Render for Day10 {
+    fn render(x_history: &Vec<i32>) -> Vec<Image> {
+        let mut screen = Image::new(
+            SCREEN_WIDTH * PIXEL_SCALE,
+            SCREEN_HEIGHT * PIXEL_SCALE,
+            DARK_COLOR,
+        );
+        for (index, is_lit) in get_lit_pixels(x_history).into_iter().enumerate() {
+            if is_lit {
+                let pixel = Point::new(index % SCREEN_WIDTH, index / SCREEN_WIDTH);
+                screen.fill_cell(pixel, PIXEL_SCALE, LIT_COLOR);
+            }
+        }
+
+        vec![screen]
+    }
+}
+
+// A pixel is lit when the sprite, three pixels wide, is over it as it is drawn.
+fn get_lit_pixels(x_history: &[i32]) -> Vec<bool> {
+    x_history[..SCREEN_WIDTH * SCREEN_HEIGHT]
+        .iter()
+        .enumerate()
+        .map(|(index, &x)| {
+            let singed_index = i32::try_from(index % SCREEN_WIDTH).unwrap();
+            singed_index - 1 <= x && x <= singed_index + 1
+        })
+        .collect()
+}
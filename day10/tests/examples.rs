@@ -0,0 +1,22 @@
+use common::{solve, Part};
+use day10::Day10;
+
+const CRT: &str = "\
+##..##..##..##..##..##..##..##..##..##..\n\
+###...###...###...###...###...###...###.\n\
+####....####....####....####....####....\n\
+#####.....#####.....#####.....#####.....\n\
+######......######......######......####\n\
+#######.......#######.......#######.....";
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day10>(EXAMPLE, Part::One).unwrap(), "13140");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day10>(EXAMPLE, Part::Two).unwrap(), CRT);
+}
@@ -0,0 +1,27 @@
+use common::image::render;
+use common::Point;
+use day10::Day10;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn render_draws_the_screen() {
+    let images = render::<Day10>(EXAMPLE).unwrap();
+
+    assert_eq!(images.len(), 1);
+    let screen = &images[0];
+    assert_eq!((screen.width(), screen.height()), (400, 60));
+
+    // The first line of the screen reads `##..##..##..`.
+    let lit_color = screen[Point::new(0, 0)];
+    let first_line: String = (0..12)
+        .map(|x| {
+            if screen[Point::new(10 * x + 5, 5)] == lit_color {
+                '#'
+            } else {
+                '.'
+            }
+        })
+        .collect();
+    assert_eq!(first_line, "##..##..##..");
+}
@@ -0,0 +1,358 @@
+use common::error::{column_of, parse_number};
+use common::{Generator, Operator, ParseError, Random, SolveError, Solver};
+use std::collections::VecDeque;
+
+const NUMBER_OF_ROUNDS: usize = 10000;
+const NUMBER_OF_ROUNDS_WITH_RELIEF: usize = 20;
+const RELIEF_FACTOR: i64 = 3;
+
+// Line and column of a target monkey in the input, then its index.
+type Target = (usize, usize, usize);
+
+#[derive(Debug, Clone)]
+enum OperationMember {
+    Constant(i64),
+    Old,
+}
+
+#[derive(Debug, Clone)]
+struct Operation {
+    left_member: OperationMember,
+    operator: Operator,
+    right_member: OperationMember,
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    item_list: VecDeque<i64>,
+    operation: Operation,
+    divisible_value_test: i64,
+    target_true: usize,
+    target_false: usize,
+    inspect_count: usize,
+}
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input = Vec<Monkey>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+        let mut monkeys: Vec<Monkey> = Vec::new();
+        let mut monkey_information: Vec<(usize, &str)> = Vec::new();
+        let mut targets: Vec<Target> = Vec::new();
+
+        for (line_index, line) in input.lines().chain([""]).enumerate() {
+            if !line.is_empty() {
+                monkey_information.push((line_index, line));
+            } else if !monkey_information.is_empty() {
+                let monkey_index = monkeys.len();
+                let (monkey, monkey_targets) = parse_monkey(&monkey_information)?;
+
+                for &(target_line_index, column_index, target) in monkey_targets.iter() {
+                    if target == monkey_index {
+                        return Err(ParseError::at(
+                            target_line_index,
+                            column_index,
+                            "a monkey other than itself",
+                        ));
+                    }
+                }
+
+                monkeys.push(monkey);
+                targets.extend(monkey_targets);
+                monkey_information = Vec::new();
+            }
+        }
+
+        // A single monkey would have nobody to throw its items to.
+        if monkeys.len() < 2 {
+            return Err(ParseError::at(
+                input.lines().count(),
+                0,
+                "at least two monkeys",
+            ));
+        }
+
+        for (target_line_index, column_index, target) in targets.into_iter() {
+            if target >= monkeys.len() {
+                return Err(ParseError::at(
+                    target_line_index,
+                    column_index,
+                    format!("a monkey below {}", monkeys.len()),
+                ));
+            }
+        }
+
+        Ok(monkeys)
+    }
+
+    fn part_one(monkeys: &Vec<Monkey>) -> Result<usize, SolveError> {
+        get_monkey_business(monkeys.clone(), NUMBER_OF_ROUNDS_WITH_RELIEF, true)
+            .ok_or_else(|| SolveError::new("the worry levels overflow"))
+    }
+
+    fn part_two(monkeys: &Vec<Monkey>) -> Result<usize, SolveError> {
+        get_monkey_business(monkeys.clone(), NUMBER_OF_ROUNDS, false)
+            .ok_or_else(|| SolveError::new("the worry levels overflow"))
+    }
+}
+
+// The size is the number of monkeys, between 2 and 9: the product of their
+// divisors, distinct primes, must stay small enough for a worry level below it
+// to be squared. As in the real inputs, a monkey squares the worry levels, two
+// multiply them and the others add to them, but two monkeys alone passing the
+// items to each other only multiply and add. Monkeys whose worry levels would
+// overflow before the relief are drawn again.
+impl Generator for Day11 {
+    const DEFAULT_SIZE: usize = 8;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        const DIVISORS: [i64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+        let monkey_count = size.clamp(2, DIVISORS.len());
+
+        loop {
+            let mut divisors = DIVISORS;
+            random.shuffle(&mut divisors);
+
+            let descriptions: Vec<String> = (0..monkey_count)
+                .map(|monkey_index| {
+                    let items: Vec<String> = (0..random.between(1, 8))
+                        .map(|_| random.between(50, 99).to_string())
+                        .collect();
+                    let operation_index = if monkey_count > 2 {
+                        monkey_index
+                    } else {
+                        monkey_index + 2
+                    };
+                    let operation = match operation_index {
+                        0 => String::from("old * old"),
+                        1 | 2 => format!("old * {}", random.between(2, 19)),
+                        _ => format!("old + {}", random.between(1, 8)),
+                    };
+                    let mut pick_target =
+                        || (monkey_index + 1 + random.below(monkey_count - 1)) % monkey_count;
+                    let target_true = pick_target();
+                    let target_false = pick_target();
+
+                    format!(
+                        "Monkey {monkey_index}:\n  Starting items: {}\n  Operation: new = {operation}\n  Test: divisible by {}\n    If true: throw to monkey {target_true}\n    If false: throw to monkey {target_false}\n",
+                        items.join(", "),
+                        divisors[monkey_index],
+                    )
+                })
+                .collect();
+            let input = descriptions.join("\n");
+
+            let monkeys = Day11::parse(&input).unwrap();
+            if get_monkey_business(monkeys, NUMBER_OF_ROUNDS_WITH_RELIEF, true).is_some() {
+                return input;
+            }
+        }
+    }
+}
+
+// Nothing when a worry level or the monkey business gets too large to be held.
+fn get_monkey_business(
+    mut monkeys: Vec<Monkey>,
+    number_of_rounds: usize,
+    is_relieved: bool,
+) -> Option<usize> {
+    let monkey_common_multiple = monkeys.iter().try_fold(1_i64, |common_multiple, monkey| {
+        common_multiple.checked_mul(monkey.divisible_value_test)
+    })?;
+
+    for _ in 0..number_of_rounds {
+        for monkey_index in 0..monkeys.len() {
+            let mut items_to_append_true: VecDeque<i64> = VecDeque::new();
+            let mut items_to_append_false: VecDeque<i64> = VecDeque::new();
+            let monkey_target_true;
+            let monkey_target_false;
+
+            {
+                let monkey = &mut monkeys[monkey_index];
+                monkey_target_true = monkey.target_true;
+                monkey_target_false = monkey.target_false;
+
+                while let Some(mut item_value) = monkey.item_list.pop_front() {
+                    monkey.inspect_count += 1;
+
+                    let left_member = match monkey.operation.left_member {
+                        OperationMember::Old => item_value,
+                        OperationMember::Constant(constant) => constant,
+                    };
+                    let right_member = match monkey.operation.right_member {
+                        OperationMember::Old => item_value,
+                        OperationMember::Constant(constant) => constant,
+                    };
+                    item_value = monkey
+                        .operation
+                        .operator
+                        .checked_apply(left_member, right_member)?;
+                    if is_relieved {
+                        item_value /= RELIEF_FACTOR;
+                    } else {
+                        item_value %= monkey_common_multiple;
+                    }
+
+                    if item_value % monkey.divisible_value_test == 0 {
+                        items_to_append_true.push_back(item_value);
+                    } else {
+                        items_to_append_false.push_back(item_value);
+                    }
+                }
+            }
+
+            while let Some(item_value) = items_to_append_true.pop_front() {
+                monkeys[monkey_target_true].item_list.push_back(item_value);
+            }
+            while let Some(item_value) = items_to_append_false.pop_front() {
+                monkeys[monkey_target_false].item_list.push_back(item_value);
+            }
+        }
+    }
+
+    let mut monkey_inspection_counts: Vec<usize> =
+        monkeys.iter().map(|monkey| monkey.inspect_count).collect();
+    monkey_inspection_counts.sort();
+    monkey_inspection_counts.reverse();
+
+    monkey_inspection_counts[0].checked_mul(monkey_inspection_counts[1])
+}
+
+// Parses the six lines describing a monkey, along with the position of the
+// monkeys it throws to so that they can be checked once every monkey is known.
+fn parse_monkey(monkey_information: &[(usize, &str)]) -> Result<(Monkey, [Target; 2]), ParseError> {
+    get_monkey_line(monkey_information, 0, "Monkey ", "`Monkey <number>:`")?;
+
+    let (line_index, line, items) = get_monkey_line(
+        monkey_information,
+        1,
+        "  Starting items: ",
+        "`  Starting items: <items>`",
+    )?;
+    let item_list: VecDeque<i64> = items
+        .split(", ")
+        .filter(|item| !item.is_empty())
+        .map(|item| parse_number::<i64>(line_index, line, item))
+        .collect::<Result<VecDeque<i64>, ParseError>>()?;
+
+    let (line_index, line, operation) = get_monkey_line(
+        monkey_information,
+        2,
+        "  Operation: new = ",
+        "`  Operation: new = <member> <operator> <member>`",
+    )?;
+    let operation_data: Vec<&str> = operation.split(' ').collect();
+    if operation_data.len() != 3 {
+        return Err(ParseError::at(
+            line_index,
+            column_of(line, operation),
+            "`<member> <operator> <member>`",
+        ));
+    }
+    let operation = Operation {
+        left_member: parse_member(line_index, line, operation_data[0])?,
+        right_member: parse_member(line_index, line, operation_data[2])?,
+        operator: parse_operator(line_index, line, operation_data[1])?,
+    };
+
+    let (line_index, line, divisible_value_test) = get_monkey_line(
+        monkey_information,
+        3,
+        "  Test: divisible by ",
+        "`  Test: divisible by <number>`",
+    )?;
+    let divisible_value_test = match parse_number::<i64>(line_index, line, divisible_value_test)? {
+        divisible_value_test if divisible_value_test > 0 => divisible_value_test,
+        _ => {
+            return Err(ParseError::at(
+                line_index,
+                column_of(line, divisible_value_test),
+                "a positive number",
+            ))
+        }
+    };
+
+    let (true_line_index, line, target_true_value) = get_monkey_line(
+        monkey_information,
+        4,
+        "    If true: throw to monkey ",
+        "`    If true: throw to monkey <number>`",
+    )?;
+    let target_true = parse_number::<usize>(true_line_index, line, target_true_value)?;
+    let true_column_index = column_of(line, target_true_value);
+
+    let (false_line_index, line, target_false_value) = get_monkey_line(
+        monkey_information,
+        5,
+        "    If false: throw to monkey ",
+        "`    If false: throw to monkey <number>`",
+    )?;
+    let target_false = parse_number::<usize>(false_line_index, line, target_false_value)?;
+    let false_column_index = column_of(line, target_false_value);
+
+    let monkey = Monkey {
+        item_list,
+        operation,
+        divisible_value_test,
+        target_true,
+        target_false,
+        inspect_count: 0,
+    };
+
+    Ok((
+        monkey,
+        [
+            (true_line_index, true_column_index, target_true),
+            (false_line_index, false_column_index, target_false),
+        ],
+    ))
+}
+
+// Returns the line at `offset` in the description of a monkey, and what
+// follows its expected `prefix`.
+fn get_monkey_line<'a>(
+    monkey_information: &[(usize, &'a str)],
+    offset: usize,
+    prefix: &str,
+    expected: &str,
+) -> Result<(usize, &'a str, &'a str), ParseError> {
+    let Some(&(line_index, line)) = monkey_information.get(offset) else {
+        let (last_line_index, _) = monkey_information[monkey_information.len() - 1];
+        return Err(ParseError::at(last_line_index + 1, 0, expected));
+    };
+
+    match line.strip_prefix(prefix) {
+        Some(value) => Ok((line_index, line, value)),
+        None => Err(ParseError::at(line_index, 0, expected)),
+    }
+}
+
+fn parse_member(
+    line_index: usize,
+    line: &str,
+    member: &str,
+) -> Result<OperationMember, ParseError> {
+    match member {
+        "old" => Ok(OperationMember::Old),
+        _ => Ok(OperationMember::Constant(parse_number::<i64>(
+            line_index, line, member,
+        )?)),
+    }
+}
+
+fn parse_operator(line_index: usize, line: &str, operator: &str) -> Result<Operator, ParseError> {
+    match Operator::from_symbol(operator) {
+        Some(operator @ (Operator::Add | Operator::Multiply)) => Ok(operator),
+        _ => Err(ParseError::at(
+            line_index,
+            column_of(line, operator),
+            "`+` or `*`",
+        )),
+    }
+}
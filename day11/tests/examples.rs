@@ -0,0 +1,27 @@
+use common::{solve, Error, ParseError, Part};
+use day11::Day11;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day11>(EXAMPLE, Part::One).unwrap(), "10605");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day11>(EXAMPLE, Part::Two).unwrap(), "2713310158");
+}
+
+#[test]
+fn single_monkey() {
+    let input = "Monkey 0:\n  Starting items: 79\n  Operation: new = old * 19\n  Test: divisible by 23\n    If true: throw to monkey 1\n    If false: throw to monkey 1\n";
+    assert_eq!(
+        solve::<Day11>(input, Part::One),
+        Err(Error::Parse(ParseError::at(6, 0, "at least two monkeys")))
+    );
+    assert_eq!(
+        solve::<Day11>("", Part::One),
+        Err(Error::Parse(ParseError::at(0, 0, "at least two monkeys")))
+    );
+}
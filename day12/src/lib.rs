@@ -0,0 +1,164 @@
+use common::search::{bfs, Path};
+use common::{Generator, Grid, Image, ParseError, Point, Random, Render, Rgb, SolveError, Solver};
+
+const CELL_SCALE: usize = 6;
+const LOWEST_COLOR: Rgb = Rgb(30, 90, 40);
+const HIGHEST_COLOR: Rgb = Rgb(235, 235, 225);
+const PATH_COLOR: Rgb = Rgb(220, 30, 30);
+const START_COLOR: Rgb = Rgb(40, 80, 230);
+const END_COLOR: Rgb = Rgb(250, 200, 0);
+
+pub struct Heightmap {
+    altitudes: Grid<u32>,
+    start_position: Point<usize>,
+    end_position: Point<usize>,
+}
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input = Heightmap;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Heightmap, ParseError> {
+        let letters = Grid::parse(input, "a lowercase letter, `S` or `E`", |letter| {
+            matches!(letter, 'a'..='z' | 'S' | 'E').then_some(letter)
+        })?;
+
+        let find_unique_position = |searched_letter: char| {
+            let mut positions = letters
+                .points()
+                .filter(|&position| letters[position] == searched_letter);
+            let position = positions.next().ok_or_else(|| {
+                ParseError::at(letters.height(), 0, format!("`{searched_letter}`"))
+            })?;
+            if let Some(duplicate_position) = positions.next() {
+                return Err(ParseError::at(
+                    duplicate_position.y,
+                    duplicate_position.x,
+                    format!("a single `{searched_letter}`"),
+                ));
+            }
+            Ok(position)
+        };
+
+        Ok(Heightmap {
+            start_position: find_unique_position('S')?,
+            end_position: find_unique_position('E')?,
+            altitudes: letters.map(|&letter| letter_to_altitude(letter)),
+        })
+    }
+
+    fn part_one(heightmap: &Heightmap) -> Result<usize, SolveError> {
+        find_path(heightmap, |position| *position == heightmap.start_position)
+            .map(|path| path.cost)
+            .ok_or_else(|| SolveError::new("no path climbs from the start to the best signal"))
+    }
+
+    fn part_two(heightmap: &Heightmap) -> Result<usize, SolveError> {
+        find_path(heightmap, |position| heightmap.altitudes[*position] == 0)
+            .map(|path| path.cost)
+            .ok_or_else(|| {
+                SolveError::new("no path climbs from the lowest squares to the best signal")
+            })
+    }
+}
+
+// The size is the number of columns, at least 26, the map having a quarter of
+// it as lines. A staircase of letters going from `S` to `E` is laid over
+// random ones so that a path always climbs to the best signal.
+impl Generator for Day12 {
+    const DEFAULT_SIZE: usize = 162;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        let width = size.max(26);
+        let height = (width / 4).max(3);
+        let mut letters = Grid::new(height, width, 'a');
+        for position in letters.points().collect::<Vec<_>>() {
+            letters[position] = (b'a' + random.below(26) as u8) as char;
+        }
+
+        let end_x = 25 + random.below(width - 25);
+        let end_y = random.below(height);
+        let mut position = Point::new(0, random.below(height));
+        let mut path = vec![position];
+        while position != Point::new(end_x, end_y) {
+            if position.y != end_y && (position.x == end_x || random.chance(0.3)) {
+                position.y = if position.y < end_y {
+                    position.y + 1
+                } else {
+                    position.y - 1
+                };
+            } else {
+                position.x += 1;
+            }
+            path.push(position);
+        }
+
+        // The 25 steps of the path climbing by one, the others staying level.
+        let mut climbing_steps: Vec<usize> = (1..path.len()).collect();
+        random.shuffle(&mut climbing_steps);
+        climbing_steps.truncate(25);
+        let mut altitude = 0;
+        for (step, &position) in path.iter().enumerate() {
+            if climbing_steps.contains(&step) {
+                altitude += 1;
+            }
+            letters[position] = (b'a' + altitude) as char;
+        }
+        letters[path[0]] = 'S';
+        letters[path[path.len() - 1]] = 'E';
+
+        letters.render(|&letter| letter) + "\n"
+    }
+}
+
+// The altitudes from the valleys to the summits, with the shortest path from
+// the start position.
+impl Render for Day12 {
+    fn render(heightmap: &Heightmap) -> Vec<Image> {
+        let mut image = Image::from_grid(&heightmap.altitudes, CELL_SCALE, |&altitude| {
+            LOWEST_COLOR.blend(HIGHEST_COLOR, altitude as f64 / 25.)
+        });
+        let path = find_path(heightmap, |position| *position == heightmap.start_position);
+        for &position in path.iter().flat_map(|path| path.nodes.iter()) {
+            image.fill_cell(position, CELL_SCALE, PATH_COLOR);
+        }
+        image.fill_cell(heightmap.start_position, CELL_SCALE, START_COLOR);
+        image.fill_cell(heightmap.end_position, CELL_SCALE, END_COLOR);
+
+        vec![image]
+    }
+}
+
+fn letter_to_altitude(letter: char) -> u32 {
+    if letter == 'S' {
+        return 0;
+    }
+    if letter == 'E' {
+        return 25;
+    }
+    letter as u32 - 97
+}
+
+// Walks down from the end position, so that every position reached is one the
+// hiker could climb from. Nothing when no goal climbs to the end.
+fn find_path(
+    heightmap: &Heightmap,
+    is_goal: impl Fn(&Point<usize>) -> bool,
+) -> Option<Path<Point<usize>, usize>> {
+    let altitudes = &heightmap.altitudes;
+    bfs(
+        heightmap.end_position,
+        |&current_position| {
+            let current_position_altitude = altitudes[current_position];
+            altitudes
+                .neighbours(current_position)
+                .filter(move |&neighbor| altitudes[neighbor] + 1 >= current_position_altitude)
+        },
+        is_goal,
+    )
+}
@@ -0,0 +1,24 @@
+use common::{solve, Error, Part, SolveError};
+use day12::Day12;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day12>(EXAMPLE, Part::One).unwrap(), "31");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day12>(EXAMPLE, Part::Two).unwrap(), "29");
+}
+
+#[test]
+fn no_path_gives_no_answer() {
+    assert_eq!(
+        solve::<Day12>("SbE\n", Part::One),
+        Err(Error::Solve(SolveError::new(
+            "no path climbs from the start to the best signal"
+        )))
+    );
+}
@@ -0,0 +1,149 @@
+use crate::packet::Packet;
+use common::stream::{lines, StreamError};
+use common::{Generator, ParseError, Random, SolveError, Solver, Stream};
+use std::io::BufRead;
+
+pub mod packet;
+
+const MAX_PACKET_DEPTH: usize = 3;
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Input = Vec<Packet>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<Packet>, ParseError> {
+        let packets = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(line_index, line)| Packet::parse(line_index, line))
+            .collect::<Result<Vec<Packet>, ParseError>>()?;
+
+        if packets.len() % 2 != 0 {
+            return Err(ParseError::at(input.lines().count(), 0, "a second packet"));
+        }
+
+        Ok(packets)
+    }
+
+    fn part_one(packets: &Vec<Packet>) -> Result<usize, SolveError> {
+        Ok(packets
+            .chunks(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[0] < pair[1])
+            .map(|(index, _)| index + 1)
+            .sum())
+    }
+
+    fn part_two(packets: &Vec<Packet>) -> Result<usize, SolveError> {
+        let lower_packet_counts = get_divider_packets().map(|divider_packet| {
+            packets
+                .iter()
+                .filter(|&packet| packet < &divider_packet)
+                .count()
+        });
+
+        Ok(get_decoder_key(lower_packet_counts))
+    }
+}
+
+// Each pair is compared as soon as it is read, and only the number of packets
+// below each divider packet is kept.
+impl Stream for Day13 {
+    fn stream(reader: impl BufRead) -> Result<(usize, usize), StreamError> {
+        let divider_packets = get_divider_packets();
+        let mut lower_packet_counts = [0; 2];
+        let mut left_packet: Option<Packet> = None;
+        let mut pair_count = 0;
+        let mut ordered_pair_index_sum = 0;
+        let mut line_count = 0;
+
+        for line in lines(reader) {
+            let (line_index, line) = line?;
+            line_count = line_index + 1;
+            if line.is_empty() {
+                continue;
+            }
+
+            let packet = Packet::parse(line_index, &line)?;
+            for (lower_packet_count, divider_packet) in
+                lower_packet_counts.iter_mut().zip(&divider_packets)
+            {
+                if &packet < divider_packet {
+                    *lower_packet_count += 1;
+                }
+            }
+
+            match left_packet.take() {
+                None => left_packet = Some(packet),
+                Some(left_packet) => {
+                    pair_count += 1;
+                    if left_packet < packet {
+                        ordered_pair_index_sum += pair_count;
+                    }
+                }
+            }
+        }
+
+        if left_packet.is_some() {
+            return Err(ParseError::at(line_count, 0, "a second packet").into());
+        }
+
+        Ok((ordered_pair_index_sum, get_decoder_key(lower_packet_counts)))
+    }
+}
+
+fn get_divider_packets() -> [Packet; 2] {
+    [2, 6].map(|value| {
+        Packet::List(Vec::from([Packet::List(Vec::from([Packet::Integer(
+            value,
+        )]))]))
+    })
+}
+
+// The positions of the divider packets once sorted among the others follow
+// from the number of packets below each one, the first divider being itself
+// below the second one.
+fn get_decoder_key(lower_packet_counts: [usize; 2]) -> usize {
+    (lower_packet_counts[0] + 1) * (lower_packet_counts[1] + 2)
+}
+
+// The size is the number of pairs. The packets are random lists nested up to
+// four levels deep, the two packets of a pair never being equal.
+impl Generator for Day13 {
+    const DEFAULT_SIZE: usize = 150;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        let mut pairs: Vec<String> = Vec::with_capacity(size);
+        for _ in 0..size {
+            let left_packet = generate_list(random, 0);
+            let mut right_packet = generate_list(random, 0);
+            while right_packet == left_packet {
+                right_packet = generate_list(random, 0);
+            }
+            pairs.push(format!("{left_packet}\n{right_packet}\n"));
+        }
+
+        pairs.join("\n")
+    }
+}
+
+fn generate_list(random: &mut Random, depth: usize) -> Packet {
+    let length = random.below(6);
+    let list = (0..length)
+        .map(|_| {
+            if depth < MAX_PACKET_DEPTH && random.chance(0.3) {
+                generate_list(random, depth + 1)
+            } else {
+                Packet::Integer(random.below(11) as u32)
+            }
+        })
+        .collect();
+
+    Packet::List(list)
+}
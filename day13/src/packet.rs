@@ -1,5 +1,10 @@
+use common::ParseError;
 use std::cmp::min;
 use std::cmp::Ordering;
+use std::fmt;
+
+// Bounds the nesting of the lists, which are parsed and compared recursively.
+const MAX_NESTING_DEPTH: usize = 256;
 
 #[derive(Debug, Clone)]
 pub enum Packet {
@@ -31,9 +36,28 @@ impl PartialEq for Packet {
 
 impl Eq for Packet {}
 
+// Writes the packet back as it is in the input.
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Packet::Integer(value) => write!(f, "{value}"),
+            Packet::List(list) => {
+                write!(f, "[")?;
+                for (index, packet) in list.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{packet}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
 impl Packet {
-    pub fn from(line: String) -> Packet {
-        parse_packet(line)
+    pub fn parse(line_index: usize, line: &str) -> Result<Packet, ParseError> {
+        parse_packet(line_index, line)
     }
 }
 
@@ -62,7 +86,7 @@ fn are_integers_ordered(left_value: &u32, right_value: &u32) -> Option<bool> {
     }
 }
 
-fn are_lists_ordered(left_list: &Vec<Packet>, right_list: &Vec<Packet>) -> Option<bool> {
+fn are_lists_ordered(left_list: &[Packet], right_list: &[Packet]) -> Option<bool> {
     let min_len = min(left_list.len(), right_list.len());
     for index in 0..min_len {
         let are_element_ordered = are_ordered(&left_list[index], &right_list[index]);
@@ -76,20 +100,43 @@ fn are_lists_ordered(left_list: &Vec<Packet>, right_list: &Vec<Packet>) -> Optio
         return None;
     }
 
-    return Some(left_list.len() <= right_list.len());
+    Some(left_list.len() <= right_list.len())
 }
 
-fn parse_packet(line: String) -> Packet {
-    let (packet, _line_size) = parse_packet_aux(&line);
-    packet
+fn parse_packet(line_index: usize, line: &str) -> Result<Packet, ParseError> {
+    let (packet, line_size) = parse_packet_aux(line_index, line, 0, 0)?;
+
+    if line_size + 1 != line.len() {
+        return Err(ParseError::at(
+            line_index,
+            line_size + 1,
+            "the end of the line",
+        ));
+    }
+
+    Ok(packet)
 }
 
-fn parse_packet_aux(line: &str) -> (Packet, usize) {
-    if !line.starts_with('[') {
-        panic!("Packet does not start with `[`");
+// Parses the list starting at `start`, nested in `depth` lists, and returns it
+// with the index of its closing bracket.
+fn parse_packet_aux(
+    line_index: usize,
+    line: &str,
+    start: usize,
+    depth: usize,
+) -> Result<(Packet, usize), ParseError> {
+    if !line[start..].starts_with('[') {
+        return Err(ParseError::at(line_index, start, "`[`"));
+    }
+    if depth == MAX_NESTING_DEPTH {
+        return Err(ParseError::at(
+            line_index,
+            start,
+            format!("lists nested at most {MAX_NESTING_DEPTH} deep"),
+        ));
     }
 
-    let mut index = 1;
+    let mut index = start + 1;
     let mut packet_list: Vec<Packet> = Vec::new();
 
     let mut current_value = String::new();
@@ -98,28 +145,38 @@ fn parse_packet_aux(line: &str) -> (Packet, usize) {
         let char = line.as_bytes()[index] as char;
 
         if char == '[' {
-            let (nested_packet, str_size) = parse_packet_aux(&line[index..]);
+            let (nested_packet, end_index) = parse_packet_aux(line_index, line, index, depth + 1)?;
             packet_list.push(nested_packet);
-            index += str_size + 1;
+            index = end_index + 1;
             continue;
         }
 
         if char == ',' || char == ']' {
             if !current_value.is_empty() {
-                let parsed_current_value = current_value.parse::<u32>().unwrap();
+                let parsed_current_value = current_value.parse::<u32>().map_err(|_| {
+                    ParseError::at(line_index, index - current_value.len(), "a number")
+                })?;
                 packet_list.push(Packet::Integer(parsed_current_value));
                 current_value = String::new();
             }
 
             if char == ']' {
-                return (Packet::List(packet_list), index);
+                return Ok((Packet::List(packet_list), index));
             }
             index += 1;
             continue;
         }
+
+        if !char.is_ascii_digit() {
+            return Err(ParseError::at(
+                line_index,
+                index,
+                "a digit, `,`, `[` or `]`",
+            ));
+        }
         current_value.push(char);
         index += 1;
     }
 
-    panic!("Error parsing packet.");
+    Err(ParseError::at(line_index, line.len(), "`]`"))
 }
@@ -0,0 +1,14 @@
+use common::{solve, Part};
+use day13::Day13;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day13>(EXAMPLE, Part::One).unwrap(), "13");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day13>(EXAMPLE, Part::Two).unwrap(), "140");
+}
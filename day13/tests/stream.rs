@@ -0,0 +1,25 @@
+use common::generator;
+use common::test_support::assert_stream_matches;
+use common::Solver;
+use day13::Day13;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn streaming_the_example_gives_the_same_answers() {
+    assert_stream_matches::<Day13>(EXAMPLE);
+}
+
+#[test]
+fn streaming_generated_inputs_gives_the_same_answers() {
+    for seed in 0..50 {
+        assert_stream_matches::<Day13>(&generator::generate::<Day13>(seed, Some(20)));
+    }
+}
+
+#[test]
+fn streaming_reports_the_same_parse_error() {
+    let input = "[1,2]\n[3]\n\n[4]\n";
+    assert!(Day13::parse(input).is_err());
+    assert_stream_matches::<Day13>(input);
+}
@@ -0,0 +1,321 @@
+use common::error::{column_of, parse_number};
+use common::{
+    Animation, CompassDirection, Generator, Grid, Image, ParseError, Point, Random, Recording,
+    Render, Rgb, SolveError, Solver,
+};
+use std::cmp::{max, min};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+const SAND_SOURCE: Point<u32> = Point::new(500, 0);
+// Bounds the coordinates, the cave growing with the square of its depth.
+const MAX_COORDINATE: u32 = 1000;
+const TILE_SCALE: usize = 3;
+// Frames of the rendered animation, the units of sand being sampled evenly.
+const MAX_FRAME_COUNT: usize = 150;
+const AIR_COLOR: Rgb = Rgb(25, 20, 30);
+const ROCK_COLOR: Rgb = Rgb(120, 110, 110);
+const SAND_COLOR: Rgb = Rgb(230, 190, 100);
+const SOURCE_COLOR: Rgb = Rgb(240, 60, 40);
+
+// Sand only ever falls diagonally from the source, so the cave is cut to the
+// triangle below it: the tiles of line `y` span `SAND_SOURCE.x ± y`, and the
+// floor is two lines below the deepest rock.
+pub struct Cave {
+    tiles: Grid<Tile>,
+    source: Point<usize>,
+}
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Input = Cave;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Cave, ParseError> {
+        let mut walls: Vec<Point<u32>> = Vec::new();
+        for (line_index, line) in input.lines().enumerate() {
+            let corners: Vec<&str> = line.split(" -> ").collect();
+            for (start_corner, end_corner) in corners.iter().zip(corners.iter().skip(1)) {
+                let start_position = parse_position(line_index, line, start_corner)?;
+                let end_position = parse_position(line_index, line, end_corner)?;
+
+                let intermediate_positions =
+                    get_intermediate_positions(&start_position, &end_position).ok_or_else(
+                        || {
+                            ParseError::at(
+                                line_index,
+                                column_of(line, end_corner),
+                                "a corner aligned with the previous one",
+                            )
+                        },
+                    )?;
+                walls.extend(intermediate_positions);
+            }
+        }
+
+        let Some(deepest_wall) = walls.iter().map(|wall| wall.y).max() else {
+            return Err(ParseError::at(0, 0, "a path like `498,4 -> 498,6`"));
+        };
+
+        let source = Point::new(deepest_wall as usize + 1, 0);
+        let mut tiles = Grid::new(deepest_wall as usize + 2, 2 * source.x + 1, Tile::Air);
+        for wall in walls {
+            let x = (wall.x as usize + source.x).checked_sub(SAND_SOURCE.x as usize);
+            if let Some(tile) = x.and_then(|x| tiles.get_mut(Point::new(x, wall.y as usize))) {
+                *tile = Tile::Rock;
+            }
+        }
+
+        Ok(Cave { tiles, source })
+    }
+
+    fn part_one(cave: &Cave) -> Result<usize, SolveError> {
+        Ok(count_sand(&pour_sand(cave, false, |_| true)))
+    }
+
+    fn part_two(cave: &Cave) -> Result<usize, SolveError> {
+        Ok(count_sand(&pour_sand(cave, true, |_| true)))
+    }
+}
+
+// The size is the number of rock paths, each one turning up to three times
+// around a random corner below the source, as in the real scans.
+impl Generator for Day14 {
+    const DEFAULT_SIZE: usize = 150;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        let mut paths: Vec<String> = Vec::with_capacity(size);
+        for _ in 0..size {
+            let mut corner = Point::new(random.between(440, 560), random.between(13, 170));
+            let mut corners = vec![corner];
+            let mut is_horizontal = random.chance(0.5);
+            for _ in 0..random.between(1, 4) {
+                let length = random.between(1, 10) * if random.chance(0.5) { 1 } else { -1 };
+                if is_horizontal {
+                    corner.x += length;
+                } else {
+                    // Staying below the source, however far up the path goes.
+                    corner.y = (corner.y + length).max(2);
+                }
+                corners.push(corner);
+                is_horizontal = !is_horizontal;
+            }
+
+            let corners: Vec<String> = corners
+                .iter()
+                .map(|corner| format!("{},{}", corner.x, corner.y))
+                .collect();
+            paths.push(corners.join(" -> ") + "\n");
+        }
+
+        paths.concat()
+    }
+}
+
+impl Animation for Day14 {
+    fn animate(cave: &Cave, recording: &mut Recording) {
+        recording.record(|| render_cave(&cave.tiles, cave.source));
+        pour_sand(cave, false, |tiles| {
+            recording.record(|| render_cave(tiles, cave.source));
+            !recording.is_full()
+        });
+    }
+}
+
+// The sand piling up on the floor until it blocks the source.
+impl Render for Day14 {
+    fn render(cave: &Cave) -> Vec<Image> {
+        let sand_count = count_sand(&pour_sand(cave, true, |_| true));
+        let frame_step = sand_count.div_ceil(MAX_FRAME_COUNT).max(1);
+
+        let mut frames = vec![draw_cave(&cave.tiles, cave.source)];
+        let mut rested_sand_count = 0;
+        pour_sand(cave, true, |tiles| {
+            rested_sand_count += 1;
+            if rested_sand_count % frame_step == 0 || rested_sand_count == sand_count {
+                frames.push(draw_cave(tiles, cave.source));
+            }
+            true
+        });
+
+        frames
+    }
+}
+
+// Pours sand until it flows out of the cave or blocks the source. The cave is
+// shown to `on_rest` each time a unit of sand comes to rest, which tells
+// whether to keep pouring.
+fn pour_sand(
+    cave: &Cave,
+    has_floor: bool,
+    mut on_rest: impl FnMut(&Grid<Tile>) -> bool,
+) -> Grid<Tile> {
+    let mut tiles = cave.tiles.clone();
+
+    while let Some(sand_position) = get_new_sand_position(&tiles, cave.source, has_floor) {
+        tiles[sand_position] = Tile::Sand;
+        if !on_rest(&tiles) || sand_position == cave.source {
+            break;
+        }
+    }
+
+    tiles
+}
+
+// Draws the part of the cave holding rocks or sand, from the source down, as
+// the puzzle does.
+fn render_cave(tiles: &Grid<Tile>, source: Point<usize>) -> String {
+    let filled_positions: Vec<Point<usize>> = tiles
+        .points()
+        .filter(|&position| tiles[position] != Tile::Air)
+        .chain([source])
+        .collect();
+    let min_x = filled_positions
+        .iter()
+        .map(|position| position.x)
+        .min()
+        .unwrap();
+    let max_x = filled_positions
+        .iter()
+        .map(|position| position.x)
+        .max()
+        .unwrap();
+    let max_y = filled_positions
+        .iter()
+        .map(|position| position.y)
+        .max()
+        .unwrap();
+
+    (0..=max_y)
+        .map(|y| {
+            (min_x..=max_x)
+                .map(|x| match tiles[Point::new(x, y)] {
+                    Tile::Rock => '#',
+                    Tile::Sand => 'o',
+                    Tile::Air if Point::new(x, y) == source => '+',
+                    Tile::Air => '.',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+// Draws the whole cave, with the floor below its last line.
+fn draw_cave(tiles: &Grid<Tile>, source: Point<usize>) -> Image {
+    let mut image = Image::new(
+        tiles.width() * TILE_SCALE,
+        (tiles.height() + 1) * TILE_SCALE,
+        ROCK_COLOR,
+    );
+    for position in tiles.points() {
+        let color = match tiles[position] {
+            Tile::Air if position == source => SOURCE_COLOR,
+            Tile::Air => AIR_COLOR,
+            Tile::Rock => ROCK_COLOR,
+            Tile::Sand => SAND_COLOR,
+        };
+        image.fill_cell(position, TILE_SCALE, color);
+    }
+    image
+}
+
+fn count_sand(tiles: &Grid<Tile>) -> usize {
+    tiles
+        .points()
+        .filter(|&position| tiles[position] == Tile::Sand)
+        .count()
+}
+
+fn parse_position(
+    line_index: usize,
+    line: &str,
+    corner_str: &str,
+) -> Result<Point<u32>, ParseError> {
+    let (x, y) = corner_str.split_once(',').ok_or_else(|| {
+        ParseError::at(
+            line_index,
+            column_of(line, corner_str),
+            "a corner like `498,4`",
+        )
+    })?;
+
+    let parse_coordinate =
+        |coordinate: &str| match parse_number::<u32>(line_index, line, coordinate)? {
+            value if value <= MAX_COORDINATE => Ok(value),
+            _ => Err(ParseError::at(
+                line_index,
+                column_of(line, coordinate),
+                format!("a coordinate up to {MAX_COORDINATE}"),
+            )),
+        };
+
+    Ok(Point::new(parse_coordinate(x)?, parse_coordinate(y)?))
+}
+
+fn get_intermediate_positions(
+    start_position: &Point<u32>,
+    end_position: &Point<u32>,
+) -> Option<Vec<Point<u32>>> {
+    if start_position.y == end_position.y {
+        return Some(
+            (min(start_position.x, end_position.x)..=max(start_position.x, end_position.x))
+                .map(|x| Point::new(x, start_position.y))
+                .collect(),
+        );
+    }
+
+    if start_position.x == end_position.x {
+        return Some(
+            (min(start_position.y, end_position.y)..=max(start_position.y, end_position.y))
+                .map(|y| Point::new(start_position.x, y))
+                .collect(),
+        );
+    }
+
+    None
+}
+
+fn get_new_sand_position(
+    tiles: &Grid<Tile>,
+    source: Point<usize>,
+    has_floor: bool,
+) -> Option<Point<usize>> {
+    let mut sand_position = source;
+
+    loop {
+        // The last line is the one resting on the floor.
+        if sand_position.y + 1 == tiles.height() {
+            return has_floor.then_some(sand_position);
+        }
+
+        let next_sand_position = move_sand_one_step(sand_position, tiles);
+
+        if next_sand_position == sand_position {
+            return Some(sand_position);
+        }
+
+        sand_position = next_sand_position;
+    }
+}
+
+fn move_sand_one_step(sand: Point<usize>, tiles: &Grid<Tile>) -> Point<usize> {
+    [
+        CompassDirection::South,
+        CompassDirection::SouthWest,
+        CompassDirection::SouthEast,
+    ]
+    .into_iter()
+    .map(|direction| sand.step(direction))
+    .find(|&position| tiles[position] == Tile::Air)
+    .unwrap_or(sand)
+}
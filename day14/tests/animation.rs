@@ -0,0 +1,45 @@
+use common::animation::record;
+use day14::Day14;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn animation_shows_the_sand_coming_to_rest() {
+    let frames = record::<Day14>(EXAMPLE, 1000).unwrap();
+
+    // The empty cave, then each of the 24 units of sand coming to rest.
+    assert_eq!(frames.len(), 25);
+    assert_eq!(
+        frames[0],
+        "\
+......+...
+..........
+..........
+..........
+....#...##
+....#...#.
+..###...#.
+........#.
+........#.
+#########."
+    );
+    assert_eq!(
+        frames[24],
+        "\
+......+...
+..........
+......o...
+.....ooo..
+....#ooo##
+...o#ooo#.
+..###ooo#.
+....oooo#.
+.o.ooooo#.
+#########."
+    );
+}
+
+#[test]
+fn animation_stops_once_the_recording_is_full() {
+    assert_eq!(record::<Day14>(EXAMPLE, 3).unwrap().len(), 3);
+}
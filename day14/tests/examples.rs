@@ -0,0 +1,14 @@
+use common::{solve, Part};
+use day14::Day14;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day14>(EXAMPLE, Part::One).unwrap(), "24");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day14>(EXAMPLE, Part::Two).unwrap(), "93");
+}
@@ -0,0 +1,339 @@
+use common::error::parse_number;
+use common::{Generator, Image, ParseError, Point, Random, Render, Rgb, SolveError, Solver};
+use regex::Regex;
+use std::cmp::min;
+use std::collections::HashSet;
+
+const TUNING_FREQUENCY: u128 = 4000000;
+const IMAGE_SIZE: usize = 800;
+const SENSOR_COLORS: [Rgb; 6] = [
+    Rgb(70, 110, 180),
+    Rgb(90, 160, 110),
+    Rgb(180, 120, 70),
+    Rgb(150, 90, 170),
+    Rgb(80, 160, 170),
+    Rgb(170, 160, 80),
+];
+const SENSOR_COLOR: Rgb = Rgb::WHITE;
+const BEACON_COLOR: Rgb = Rgb(250, 220, 0);
+const DISTRESS_BEACON_COLOR: Rgb = Rgb(240, 30, 30);
+
+#[derive(Debug, Clone, Copy)]
+struct Interval {
+    start: u32,
+    end: u32,
+}
+
+impl Interval {
+    pub fn intersect(&self, other: &Self) -> bool {
+        let max_start = std::cmp::max(self.start, other.start);
+        let min_end = std::cmp::min(self.end, other.end);
+
+        max_start <= min_end
+    }
+
+    pub fn exclusive_union(&self, other: &Self) -> Vec<Interval> {
+        if !self.intersect(other) {
+            return Vec::from([*other]);
+        }
+
+        let mut exclusive_union_vec: Vec<Interval> = Vec::new();
+        if other.end >= self.start && self.start > other.start {
+            exclusive_union_vec.push(Interval {
+                start: other.start,
+                end: self.start - 1,
+            })
+        }
+        if other.start <= self.end && self.end < other.end {
+            exclusive_union_vec.push(Interval {
+                start: self.end + 1,
+                end: other.end,
+            })
+        }
+
+        exclusive_union_vec
+    }
+
+    pub fn len(&self) -> u32 {
+        self.end - self.start + 1
+    }
+}
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+/// The row looked at by part one and the largest coordinate of the square
+/// searched by part two are parameters of the puzzle, different for the
+/// example, and default to the ones of the real input.
+pub struct Day15<const ROW: i32 = 2000000, const MAX_COORDINATES: u32 = 4000000>;
+
+pub type ExampleDay15 = Day15<10, 20>;
+
+impl<const ROW: i32, const MAX_COORDINATES: u32> Solver for Day15<ROW, MAX_COORDINATES> {
+    type Input = Vec<(Point<i32>, Point<i32>)>;
+    type AnswerOne = usize;
+    type AnswerTwo = u128;
+
+    fn parse(input: &str) -> Result<Vec<(Point<i32>, Point<i32>)>, ParseError> {
+        let parser_regex = Regex::new(
+            r"^Sensor at x=(\-?\d+), y=(\-?\d+): closest beacon is at x=(\-?\d+), y=(\-?\d+)$",
+        )
+        .unwrap();
+
+        let mut sensors: Vec<(Point<i32>, Point<i32>)> = Vec::new();
+
+        for (line_index, line) in input.lines().enumerate() {
+            let regex_captures = parser_regex.captures(line).ok_or_else(|| {
+                ParseError::at(
+                    line_index,
+                    0,
+                    "`Sensor at x=<x>, y=<y>: closest beacon is at x=<x>, y=<y>`",
+                )
+            })?;
+            let parse_coordinate =
+                |group: usize| parse_number::<i32>(line_index, line, &regex_captures[group]);
+
+            let sensor_position = Point::new(parse_coordinate(1)?, parse_coordinate(2)?);
+            let beacon_position = Point::new(parse_coordinate(3)?, parse_coordinate(4)?);
+
+            sensors.push((sensor_position, beacon_position));
+        }
+
+        Ok(sensors)
+    }
+
+    fn part_one(sensors: &Vec<(Point<i32>, Point<i32>)>) -> Result<usize, SolveError> {
+        Ok(count_impossible_beacons(sensors, ROW))
+    }
+
+    fn part_two(sensors: &Vec<(Point<i32>, Point<i32>)>) -> Result<u128, SolveError> {
+        find_distress_beacon(sensors, MAX_COORDINATES)
+            .map(|distress_beacon| {
+                distress_beacon.x as u128 * TUNING_FREQUENCY + distress_beacon.y as u128
+            })
+            .ok_or_else(|| SolveError::new("every position is within the reach of a sensor"))
+    }
+}
+
+// The size is the number of sensors, at least 4. Four sensors around the
+// distress beacon leave it alone out of their reach in the searched square, the
+// others reaching random beacons closer to them than it.
+impl<const ROW: i32, const MAX_COORDINATES: u32> Generator for Day15<ROW, MAX_COORDINATES> {
+    const DEFAULT_SIZE: usize = 24;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        let size = size.max(4);
+        let max_coordinates = MAX_COORDINATES.max(2) as i32;
+        let distress_beacon = Point::new(
+            random.between(1, max_coordinates as i64 - 1) as i32,
+            random.between(1, max_coordinates as i64 - 1) as i32,
+        );
+
+        let offset = max_coordinates + 1;
+        let mut sensors: Vec<(Point<i32>, Point<i32>)> = [(1, 1), (1, -1), (-1, 1), (-1, -1)]
+            .into_iter()
+            .map(|(x_sign, y_sign)| {
+                (
+                    distress_beacon + Point::new(x_sign * offset, y_sign * offset),
+                    distress_beacon + Point::new(x_sign, 0),
+                )
+            })
+            .collect();
+
+        while sensors.len() < size {
+            let sensor = Point::new(
+                random.between(0, max_coordinates as i64) as i32,
+                random.between(0, max_coordinates as i64) as i32,
+            );
+            let distress_beacon_distance = sensor.manhattan_distance(distress_beacon) as i64;
+            if distress_beacon_distance < 2 {
+                continue;
+            }
+            let distance = random.between(1, distress_beacon_distance - 1);
+            let x_offset = random.between(-distance, distance);
+            let y_offset = (distance - x_offset.abs()) * if random.chance(0.5) { 1 } else { -1 };
+            let beacon = sensor + Point::new(x_offset as i32, y_offset as i32);
+
+            let is_new = sensors.iter().all(|&(other_sensor, other_beacon)| {
+                other_sensor != sensor && other_sensor != beacon && other_beacon != sensor
+            });
+            if is_new {
+                sensors.push((sensor, beacon));
+            }
+        }
+        random.shuffle(&mut sensors);
+
+        sensors
+            .iter()
+            .map(|(sensor, beacon)| {
+                format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                    sensor.x, sensor.y, beacon.x, beacon.y
+                )
+            })
+            .collect()
+    }
+}
+
+// The square searched for the distress beacon, each sensor covering a diamond
+// up to its closest beacon: the distress beacon is the only position left out.
+impl<const ROW: i32, const MAX_COORDINATES: u32> Render for Day15<ROW, MAX_COORDINATES> {
+    fn render(sensors: &Vec<(Point<i32>, Point<i32>)>) -> Vec<Image> {
+        let side = MAX_COORDINATES as i64 + 1;
+        let to_coordinate = |pixel: usize| (pixel as i64 * side / IMAGE_SIZE as i64) as i32;
+        let to_pixel = |coordinate: i32| {
+            (0..side)
+                .contains(&(coordinate as i64))
+                .then(|| (coordinate as i64 * IMAGE_SIZE as i64 / side) as usize)
+        };
+
+        let mut image = Image::new(IMAGE_SIZE, IMAGE_SIZE, Rgb::BLACK);
+        for y in 0..IMAGE_SIZE {
+            for x in 0..IMAGE_SIZE {
+                let position = Point::new(to_coordinate(x), to_coordinate(y));
+                let covering_sensor = sensors.iter().position(|(sensor, beacon)| {
+                    sensor.manhattan_distance(position) <= sensor.manhattan_distance(*beacon)
+                });
+                if let Some(sensor_index) = covering_sensor {
+                    image[Point::new(x, y)] = SENSOR_COLORS[sensor_index % SENSOR_COLORS.len()];
+                }
+            }
+        }
+
+        let mut draw_mark = |position: Point<i32>, size: usize, color: Rgb| {
+            if let (Some(x), Some(y)) = (to_pixel(position.x), to_pixel(position.y)) {
+                for mark_y in y.saturating_sub(size / 2)..=y + size / 2 {
+                    for mark_x in x.saturating_sub(size / 2)..=x + size / 2 {
+                        if image.contains(Point::new(mark_x, mark_y)) {
+                            image[Point::new(mark_x, mark_y)] = color;
+                        }
+                    }
+                }
+            }
+        };
+        for &(sensor, beacon) in sensors.iter() {
+            draw_mark(sensor, 4, SENSOR_COLOR);
+            draw_mark(beacon, 4, BEACON_COLOR);
+        }
+        if let Some(distress_beacon) = find_distress_beacon(sensors, MAX_COORDINATES) {
+            let distress_beacon = Point::new(distress_beacon.x as i32, distress_beacon.y as i32);
+            draw_mark(distress_beacon, 8, DISTRESS_BEACON_COLOR);
+        }
+
+        vec![image]
+    }
+}
+
+fn count_impossible_beacons(sensors: &[(Point<i32>, Point<i32>)], row: i32) -> usize {
+    let mut intervals: Vec<(i64, i64)> = sensors
+        .iter()
+        .filter_map(|(sensor_position, beacon_position)| {
+            let distance_sensor_beacon = get_distance(*sensor_position, *beacon_position);
+            let distance_sensor_row = (row as i64 - sensor_position.y as i64).abs();
+
+            if distance_sensor_row > distance_sensor_beacon {
+                return None;
+            }
+
+            let diff_distances = distance_sensor_beacon - distance_sensor_row;
+            Some((
+                sensor_position.x as i64 - diff_distances,
+                sensor_position.x as i64 + diff_distances,
+            ))
+        })
+        .collect();
+    intervals.sort();
+
+    let mut covered_count = 0;
+    let mut covered_until = i64::MIN;
+    for (start, end) in intervals.into_iter() {
+        if end <= covered_until {
+            continue;
+        }
+        covered_count += (end - start.max(covered_until + 1) + 1) as usize;
+        covered_until = end;
+    }
+
+    let beacons_on_row: HashSet<&Point<i32>> = sensors
+        .iter()
+        .map(|(_, beacon_position)| beacon_position)
+        .filter(|beacon_position| beacon_position.y == row)
+        .collect();
+
+    covered_count - beacons_on_row.len()
+}
+
+fn find_distress_beacon(
+    sensors: &[(Point<i32>, Point<i32>)],
+    max_coordinates: u32,
+) -> Option<Point<u32>> {
+    let mut impossible_beacon_intervals_list: Vec<Vec<Interval>> =
+        (0..=max_coordinates).map(|_| Vec::new()).collect();
+
+    for (sensor_position, beacon_position) in sensors.iter() {
+        for (depth_search, impossible_beacon_intervals) in
+            impossible_beacon_intervals_list.iter_mut().enumerate()
+        {
+            let distance_sensor_beacon = get_distance(*sensor_position, *beacon_position);
+            let distance_sensor_depth = (depth_search as i64 - sensor_position.y as i64).abs();
+
+            if distance_sensor_depth > distance_sensor_beacon {
+                continue;
+            }
+
+            let diff_distances = distance_sensor_beacon - distance_sensor_depth;
+
+            // An interval left of the square is skipped, rather than clamped
+            // onto its first column.
+            let start = u32::try_from(sensor_position.x as i64 - diff_distances).unwrap_or(0);
+            let Ok(end) = u32::try_from(sensor_position.x as i64 + diff_distances) else {
+                continue;
+            };
+            let end = min(max_coordinates, end);
+
+            if start > max_coordinates {
+                continue;
+            }
+
+            let mut intervals_to_insert = Vec::from([Interval { start, end }]);
+
+            for impossible_beacon_interval in impossible_beacon_intervals.iter() {
+                let mut new_intervals_to_insert: Vec<Interval> = Vec::new();
+                for interval_to_insert in intervals_to_insert.into_iter() {
+                    let exclusive_union =
+                        impossible_beacon_interval.exclusive_union(&interval_to_insert);
+
+                    new_intervals_to_insert.extend(exclusive_union);
+                }
+                intervals_to_insert = new_intervals_to_insert;
+            }
+
+            impossible_beacon_intervals.extend(intervals_to_insert);
+        }
+    }
+
+    for (y, impossible_beacon_intervals) in impossible_beacon_intervals_list.iter().enumerate() {
+        let impossible_beacon_count = impossible_beacon_intervals
+            .iter()
+            .map(|interval| interval.len())
+            .sum::<u32>();
+
+        if impossible_beacon_count < max_coordinates + 1 {
+            for x in 0..max_coordinates + 1 {
+                let contains_x = impossible_beacon_intervals
+                    .iter()
+                    .any(|interval| interval.start <= x && x <= interval.end);
+                if !contains_x {
+                    return Some(Point::new(x, y as u32));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+// The Manhattan distance, widened so that it cannot overflow whatever the
+// coordinates.
+fn get_distance(position: Point<i32>, other: Point<i32>) -> i64 {
+    (position.x as i64 - other.x as i64).abs() + (position.y as i64 - other.y as i64).abs()
+}
@@ -0,0 +1,28 @@
+use common::{solve, Error, Part, SolveError};
+use day15::ExampleDay15;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<ExampleDay15>(EXAMPLE, Part::One).unwrap(), "26");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(
+        solve::<ExampleDay15>(EXAMPLE, Part::Two).unwrap(),
+        "56000011"
+    );
+}
+
+#[test]
+fn no_distress_beacon() {
+    let input = "Sensor at x=10, y=10: closest beacon is at x=10, y=40\n";
+    assert_eq!(
+        solve::<ExampleDay15>(input, Part::Two),
+        Err(Error::Solve(SolveError::new(
+            "every position is within the reach of a sensor"
+        )))
+    );
+}
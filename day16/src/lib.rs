@@ -0,0 +1,327 @@
+use common::error::{column_of, parse_number};
+use common::search::bfs;
+use common::{Generator, ParseError, Random, SolveError, Solver};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct Valve {
+    flow_rate: u32,
+    neighbor_valves: HashSet<String>,
+}
+
+// Only the valves releasing pressure are worth walking to, so the tunnels are
+// reduced to the distances between those valves and the start valve, which
+// comes first.
+struct Network {
+    flow_rates: Vec<u32>,
+    distances: Vec<Vec<Option<u32>>>,
+}
+
+lazy_static! {
+    static ref PARSER_REGEX: Regex =
+        Regex::new(r"^Valve ([A-Z]+) has flow rate=(\d+); tunnels? leads? to valves? (.+)$")
+            .unwrap();
+}
+
+const START_VALVE: &str = "AA";
+// The opened valves are the bits of a `u64`.
+const MAX_USEFUL_VALVES: usize = 64;
+const MAX_STEP_ALONE: u32 = 30;
+const MAX_STEP_WITH_ELEPHANT: u32 = 26;
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    type Input = HashMap<String, Valve>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Result<HashMap<String, Valve>, ParseError> {
+        parse_valves(input)
+    }
+
+    fn part_one(valves: &HashMap<String, Valve>) -> Result<u32, SolveError> {
+        let network = get_network(valves);
+        let mut max_total_flow = 0;
+        explore_alone(&network, 0, MAX_STEP_ALONE, 0, 0, &mut max_total_flow);
+        Ok(max_total_flow)
+    }
+
+    // The elephant and I open disjoint sets of valves, so the best plan pairs
+    // the best total flows of two such sets.
+    fn part_two(valves: &HashMap<String, Valve>) -> Result<u32, SolveError> {
+        let network = get_network(valves);
+        let mut total_flows: HashMap<u64, u32> = HashMap::new();
+        explore(&network, 0, MAX_STEP_WITH_ELEPHANT, 0, 0, &mut total_flows);
+
+        let total_flows: Vec<(u64, u32)> = total_flows.into_iter().collect();
+        let mut max_total_flow = 0;
+        for (index, &(my_opened_valves, my_total_flow)) in total_flows.iter().enumerate() {
+            for &(elephant_opened_valves, elephant_total_flow) in total_flows[index..].iter() {
+                if my_opened_valves & elephant_opened_valves == 0 {
+                    max_total_flow = max_total_flow.max(my_total_flow + elephant_total_flow);
+                }
+            }
+        }
+        Ok(max_total_flow)
+    }
+}
+
+// The size is the number of valves, from 2 to 676 so that they all get a name
+// of two letters. A quarter of them release pressure, no more than 15 as in
+// the real scans, and the tunnels link them all into a tree with some loops.
+impl Generator for Day16 {
+    const DEFAULT_SIZE: usize = 59;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        let valve_count = size.clamp(2, 26 * 26);
+        let mut valve_names: Vec<String> = (b'A'..=b'Z')
+            .flat_map(|first| {
+                (b'A'..=b'Z').map(move |second| format!("{}{}", first as char, second as char))
+            })
+            .filter(|valve_name| valve_name != START_VALVE)
+            .collect();
+        random.shuffle(&mut valve_names);
+        valve_names.truncate(valve_count - 1);
+        valve_names.insert(0, START_VALVE.to_string());
+
+        let mut tunnels: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); valve_count];
+        let mut dig_tunnel = |start: usize, end: usize| {
+            tunnels[start].insert(end);
+            tunnels[end].insert(start);
+        };
+        for valve in 1..valve_count {
+            dig_tunnel(valve, random.below(valve));
+        }
+        for _ in 0..valve_count / 5 {
+            let (start, end) = (random.below(valve_count), random.below(valve_count));
+            if start != end {
+                dig_tunnel(start, end);
+            }
+        }
+
+        let useful_valve_count = (valve_count / 4).clamp(1, 15).min(valve_count - 1);
+        let mut valves: Vec<usize> = (0..valve_count).collect();
+        random.shuffle(&mut valves[1..]);
+        let mut flow_rates = vec![0; valve_count];
+        for &valve in valves[1..=useful_valve_count].iter() {
+            flow_rates[valve] = random.between(3, 25);
+        }
+
+        random.shuffle(&mut valves);
+        valves
+            .iter()
+            .map(|&valve| {
+                let neighbor_valves: Vec<&str> = tunnels[valve]
+                    .iter()
+                    .map(|&neighbor_valve| valve_names[neighbor_valve].as_str())
+                    .collect();
+                let tunnels = match neighbor_valves.len() {
+                    1 => "tunnel leads to valve",
+                    _ => "tunnels lead to valves",
+                };
+                format!(
+                    "Valve {} has flow rate={}; {tunnels} {}\n",
+                    valve_names[valve],
+                    flow_rates[valve],
+                    neighbor_valves.join(", ")
+                )
+            })
+            .collect()
+    }
+}
+
+fn get_network(valves: &HashMap<String, Valve>) -> Network {
+    let mut valve_names: Vec<&String> = valves
+        .iter()
+        .filter(|(valve_name, valve)| valve.flow_rate > 0 && valve_name.as_str() != START_VALVE)
+        .map(|(valve_name, _)| valve_name)
+        .collect();
+    valve_names.sort();
+    valve_names.insert(0, valves.get_key_value(START_VALVE).unwrap().0);
+
+    let distances = valve_names
+        .iter()
+        .map(|&start_valve| {
+            valve_names
+                .iter()
+                .map(|&end_valve| {
+                    let path = bfs(
+                        start_valve,
+                        |&valve_name| valves[valve_name].neighbor_valves.iter(),
+                        |&valve_name| valve_name == end_valve,
+                    );
+                    path.map(|path| path.cost as u32)
+                })
+                .collect()
+        })
+        .collect();
+
+    Network {
+        flow_rates: valve_names
+            .iter()
+            .map(|&valve_name| valves[valve_name].flow_rate)
+            .collect(),
+        distances,
+    }
+}
+
+// Walks to every valve that can still be opened in time, and opens it. An
+// opened valve releases its flow until the end, which is counted right away.
+fn get_next_valves(
+    network: &Network,
+    current_valve: usize,
+    remaining_step: u32,
+    opened_valves: u64,
+) -> impl Iterator<Item = (usize, u32, u64)> + '_ {
+    (1..network.flow_rates.len()).filter_map(move |next_valve| {
+        let distance = network.distances[current_valve][next_valve]?;
+        if opened_valves & (1 << next_valve) != 0 || distance + 1 >= remaining_step {
+            return None;
+        }
+        Some((
+            next_valve,
+            remaining_step - distance - 1,
+            opened_valves | (1 << next_valve),
+        ))
+    })
+}
+
+fn explore(
+    network: &Network,
+    current_valve: usize,
+    remaining_step: u32,
+    opened_valves: u64,
+    total_flow: u32,
+    total_flows: &mut HashMap<u64, u32>,
+) {
+    let best_total_flow = total_flows.entry(opened_valves).or_insert(0);
+    *best_total_flow = (*best_total_flow).max(total_flow);
+
+    for (next_valve, next_remaining_step, next_opened_valves) in
+        get_next_valves(network, current_valve, remaining_step, opened_valves)
+    {
+        explore(
+            network,
+            next_valve,
+            next_remaining_step,
+            next_opened_valves,
+            total_flow + network.flow_rates[next_valve] * next_remaining_step,
+            total_flows,
+        );
+    }
+}
+
+fn explore_alone(
+    network: &Network,
+    current_valve: usize,
+    remaining_step: u32,
+    opened_valves: u64,
+    total_flow: u32,
+    max_total_flow: &mut u32,
+) {
+    *max_total_flow = (*max_total_flow).max(total_flow);
+
+    let remaining_flow_upper_value =
+        get_remaining_flow_upper_value(network, opened_valves, remaining_step);
+    if total_flow + remaining_flow_upper_value <= *max_total_flow {
+        return;
+    }
+
+    for (next_valve, next_remaining_step, next_opened_valves) in
+        get_next_valves(network, current_valve, remaining_step, opened_valves)
+    {
+        explore_alone(
+            network,
+            next_valve,
+            next_remaining_step,
+            next_opened_valves,
+            total_flow + network.flow_rates[next_valve] * next_remaining_step,
+            max_total_flow,
+        );
+    }
+}
+
+// Even if every closed valve was opened at the next step, they would not
+// release more than this.
+fn get_remaining_flow_upper_value(
+    network: &Network,
+    opened_valves: u64,
+    remaining_step: u32,
+) -> u32 {
+    let closed_valves_flow_rate: u32 = network
+        .flow_rates
+        .iter()
+        .enumerate()
+        .filter(|&(valve, _)| opened_valves & (1 << valve) == 0)
+        .map(|(_, flow_rate)| flow_rate)
+        .sum();
+
+    closed_valves_flow_rate * remaining_step.saturating_sub(1)
+}
+
+fn parse_valves(input: &str) -> Result<HashMap<String, Valve>, ParseError> {
+    let mut valves: HashMap<String, Valve> = HashMap::new();
+    let mut tunnels: Vec<(usize, usize, &str)> = Vec::new();
+
+    for (line_index, line) in input.lines().enumerate() {
+        let captures = PARSER_REGEX.captures(line).ok_or_else(|| {
+            ParseError::at(
+                line_index,
+                0,
+                "`Valve <name> has flow rate=<rate>; tunnels lead to valves <names>`",
+            )
+        })?;
+
+        let valve_name = String::from(&captures[1]);
+        let flow_rate = parse_number::<u32>(line_index, line, &captures[2])?;
+        let mut neighbor_valves: HashSet<String> = HashSet::new();
+        for neighbor_valve in captures.get(3).unwrap().as_str().split(", ") {
+            tunnels.push((line_index, column_of(line, neighbor_valve), neighbor_valve));
+            neighbor_valves.insert(String::from(neighbor_valve));
+        }
+
+        valves.insert(
+            valve_name,
+            Valve {
+                flow_rate,
+                neighbor_valves,
+            },
+        );
+    }
+
+    if !valves.contains_key(START_VALVE) {
+        return Err(ParseError::at(
+            input.lines().count(),
+            0,
+            format!("a valve named `{START_VALVE}`"),
+        ));
+    }
+
+    let useful_valve_count = valves.values().filter(|valve| valve.flow_rate > 0).count();
+    if useful_valve_count > MAX_USEFUL_VALVES {
+        return Err(ParseError::at(
+            input.lines().count(),
+            0,
+            format!("at most {MAX_USEFUL_VALVES} valves with a flow rate"),
+        ));
+    }
+
+    for (line_index, column_index, neighbor_valve) in tunnels.into_iter() {
+        if !valves.contains_key(neighbor_valve) {
+            return Err(ParseError::at(
+                line_index,
+                column_index,
+                "the name of a valve",
+            ));
+        }
+    }
+
+    Ok(valves)
+}
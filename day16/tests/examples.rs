@@ -0,0 +1,14 @@
+use common::{solve, Part};
+use day16::Day16;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day16>(EXAMPLE, Part::One).unwrap(), "1651");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day16>(EXAMPLE, Part::Two).unwrap(), "1707");
+}
@@ -0,0 +1,384 @@
+use common::stream::{parse_symbols, read_symbols, StreamError};
+use common::{
+    Animation, Generator, Grid, Image, ParseError, Point, Random, Recording, Render, Rgb,
+    SolveError, Solver, Stream,
+};
+use std::cmp::max;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub enum Wind {
+    Left,
+    Right,
+}
+
+const CHAMBER_WIDTH: usize = 7;
+// Fragments of each rock, from its bottom left corner.
+const ROCKS: [&[Point<usize>]; 5] = [
+    &[
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(2, 0),
+        Point::new(3, 0),
+    ],
+    &[
+        Point::new(1, 0),
+        Point::new(0, 1),
+        Point::new(1, 1),
+        Point::new(2, 1),
+        Point::new(1, 2),
+    ],
+    &[
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(2, 0),
+        Point::new(2, 1),
+        Point::new(2, 2),
+    ],
+    &[
+        Point::new(0, 0),
+        Point::new(0, 1),
+        Point::new(0, 2),
+        Point::new(0, 3),
+    ],
+    &[
+        Point::new(0, 0),
+        Point::new(1, 0),
+        Point::new(0, 1),
+        Point::new(1, 1),
+    ],
+];
+const LEFT: Point<usize> = Point::new(1, 0);
+const RIGHT: Point<usize> = Point::new(1, 0);
+const BOTTOM: Point<usize> = Point::new(0, 1);
+const NUMBER_OF_ROCKS: u64 = 2022;
+const NUMBER_OF_ROCKS_ELEPHANTS: u64 = 1000000000000;
+// Lines below the top of the tower that the cycle detection compares: a column
+// buried deeper than that no longer shapes where the next rocks rest.
+const SURFACE_DEPTH: usize = 64;
+// Lines of the chamber drawn below its top.
+const VIEW_HEIGHT: usize = 30;
+const FRAGMENT_SCALE: usize = 4;
+const ROCK_COLORS: [Rgb; 5] = [
+    Rgb(220, 80, 70),
+    Rgb(240, 170, 50),
+    Rgb(90, 180, 90),
+    Rgb(70, 140, 220),
+    Rgb(170, 100, 200),
+];
+const AIR_COLOR: Rgb = Rgb(20, 20, 30);
+const WALL_COLOR: Rgb = Rgb(110, 110, 120);
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    type Input = Vec<Wind>;
+    type AnswerOne = u64;
+    type AnswerTwo = u64;
+
+    fn parse(input: &str) -> Result<Vec<Wind>, ParseError> {
+        let mut winds: Vec<Wind> = Vec::new();
+        parse_symbols(input, "`<` or `>`", |char| push_wind(&mut winds, char))?;
+
+        if winds.is_empty() {
+            return Err(ParseError::at(0, 0, "a jet pattern"));
+        }
+
+        Ok(winds)
+    }
+
+    fn part_one(winds: &Vec<Wind>) -> Result<u64, SolveError> {
+        Ok(get_tower_height(winds, NUMBER_OF_ROCKS))
+    }
+
+    fn part_two(winds: &Vec<Wind>) -> Result<u64, SolveError> {
+        Ok(get_tower_height(winds, NUMBER_OF_ROCKS_ELEPHANTS))
+    }
+}
+
+// The rocks fall through the whole jet pattern before their heights repeat, so
+// the pattern is kept, but read in chunks a jet at a time rather than along
+// with its text. It is the only state growing with the input.
+impl Stream for Day17 {
+    fn stream(reader: impl BufRead) -> Result<(u64, u64), StreamError> {
+        let mut winds: Vec<Wind> = Vec::new();
+        read_symbols(reader, "`<` or `>`", |char| {
+            match char_to_wind(char as char) {
+                Some(wind) => {
+                    winds.push(wind);
+                    true
+                }
+                None => false,
+            }
+        })?;
+
+        if winds.is_empty() {
+            return Err(ParseError::at(0, 0, "a jet pattern").into());
+        }
+
+        Ok((
+            get_tower_height(&winds, NUMBER_OF_ROCKS),
+            get_tower_height(&winds, NUMBER_OF_ROCKS_ELEPHANTS),
+        ))
+    }
+}
+
+// The size is the length of the jet pattern.
+impl Generator for Day17 {
+    const DEFAULT_SIZE: usize = 10091;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| *random.choose(&['<', '>']))
+            .collect::<String>()
+            + "\n"
+    }
+}
+
+impl Animation for Day17 {
+    fn animate(winds: &Vec<Wind>, recording: &mut Recording) {
+        let mut wind_index: usize = 0;
+        let mut rested_rocks: Grid<bool> = Grid::new(1, CHAMBER_WIDTH + 2, true);
+
+        for rock_count in 0..NUMBER_OF_ROCKS {
+            if recording.is_full() {
+                return;
+            }
+            drop_rock(
+                winds,
+                &mut wind_index,
+                &mut rested_rocks,
+                rock_count,
+                &mut |rested_rocks, rock| {
+                    recording.record(|| render_rested_rocks(rested_rocks, rock))
+                },
+            );
+        }
+    }
+}
+
+// The tower after the first part's rocks, each colored after its shape.
+impl Render for Day17 {
+    fn render(winds: &Vec<Wind>) -> Vec<Image> {
+        let mut wind_index: usize = 0;
+        let mut rested_rocks: Grid<bool> = Grid::new(1, CHAMBER_WIDTH + 2, true);
+        let mut fragment_colors: Vec<(Point<usize>, Rgb)> = Vec::new();
+
+        for rock_count in 0..NUMBER_OF_ROCKS {
+            // The rock rests where it was last seen moving.
+            let mut rested_rock: Vec<Point<usize>> = Vec::new();
+            drop_rock(
+                winds,
+                &mut wind_index,
+                &mut rested_rocks,
+                rock_count,
+                &mut |_, rock| rested_rock = rock.to_vec(),
+            );
+            let color = ROCK_COLORS[(rock_count % 5) as usize];
+            fragment_colors.extend(rested_rock.into_iter().map(|fragment| (fragment, color)));
+        }
+
+        let max_height = get_max_height(&rested_rocks);
+        let mut image = Image::new(
+            (CHAMBER_WIDTH + 2) * FRAGMENT_SCALE,
+            (max_height + 1) * FRAGMENT_SCALE,
+            WALL_COLOR,
+        );
+        for y in 1..=max_height {
+            for x in 1..=CHAMBER_WIDTH {
+                image.fill_cell(Point::new(x, max_height - y), FRAGMENT_SCALE, AIR_COLOR);
+            }
+        }
+        for (fragment, color) in fragment_colors {
+            let cell = Point::new(fragment.x, max_height - fragment.y);
+            image.fill_cell(cell, FRAGMENT_SCALE, color);
+        }
+
+        vec![image]
+    }
+}
+
+// The chamber ends up repeating itself: once the same rock falls with the same
+// wind on the same surface, every following rock adds the same height as one
+// cycle earlier, so the simulation stops at the first repetition.
+fn get_tower_height(winds: &[Wind], number_of_rocks: u64) -> u64 {
+    let mut wind_index: usize = 0;
+    let mut rested_rocks: Grid<bool> = Grid::new(1, CHAMBER_WIDTH + 2, true);
+
+    let mut heights: Vec<u64> = Vec::from([0]);
+    let mut seen_states: HashMap<(u64, usize, Vec<usize>), u64> = HashMap::new();
+
+    for rock_count in 0..number_of_rocks {
+        if let Some(&cycle_start) =
+            seen_states.get(&get_state(&rested_rocks, rock_count, wind_index))
+        {
+            let cycle_length = rock_count - cycle_start;
+            let cycle_height = heights[rock_count as usize] - heights[cycle_start as usize];
+            let cycle_count = (number_of_rocks - cycle_start) / cycle_length;
+            let remaining_rocks = (number_of_rocks - cycle_start) % cycle_length;
+
+            return heights[(cycle_start + remaining_rocks) as usize] + cycle_count * cycle_height;
+        }
+        seen_states.insert(get_state(&rested_rocks, rock_count, wind_index), rock_count);
+
+        drop_rock(
+            winds,
+            &mut wind_index,
+            &mut rested_rocks,
+            rock_count,
+            &mut |_, _| {},
+        );
+
+        heights.push(get_max_height(&rested_rocks) as u64);
+    }
+
+    heights[number_of_rocks as usize]
+}
+
+// Shows the falling rock to `on_move` as it appears and after each move.
+fn drop_rock(
+    winds: &[Wind],
+    wind_index: &mut usize,
+    rested_rocks: &mut Grid<bool>,
+    rock_count: u64,
+    on_move: &mut impl FnMut(&Grid<bool>, &[Point<usize>]),
+) {
+    let rock_origin = get_new_rock_origin(rested_rocks);
+    let mut rock = get_new_rock(rock_origin, rock_count);
+    on_move(rested_rocks, &rock);
+
+    loop {
+        let mut rock_after_wind = match winds[*wind_index] {
+            Wind::Left => move_rock_left(&rock),
+            Wind::Right => move_rock_right(&rock),
+        };
+        *wind_index += 1;
+        *wind_index %= winds.len();
+        if has_collision(&rock_after_wind, rested_rocks) {
+            rock_after_wind = rock;
+        }
+        on_move(rested_rocks, &rock_after_wind);
+
+        let rock_after_fall = move_rock_bottom(&rock_after_wind);
+        if has_collision(&rock_after_fall, rested_rocks) {
+            for rock_frag in rock_after_wind {
+                while rested_rocks.height() <= rock_frag.y {
+                    rested_rocks.push_line(vec![false; CHAMBER_WIDTH + 2]);
+                }
+                rested_rocks[rock_frag] = true;
+            }
+            break;
+        }
+
+        rock = rock_after_fall;
+        on_move(rested_rocks, &rock);
+    }
+}
+
+fn get_state(
+    rested_rocks: &Grid<bool>,
+    rock_count: u64,
+    wind_index: usize,
+) -> (u64, usize, Vec<usize>) {
+    let max_height = get_max_height(rested_rocks);
+    let surface = (1..=CHAMBER_WIDTH)
+        .map(|x| {
+            let column_height = rested_rocks.column(x).rposition(|&rock| rock).unwrap_or(0);
+            (max_height - column_height).min(SURFACE_DEPTH)
+        })
+        .collect();
+
+    (rock_count % 5, wind_index, surface)
+}
+
+// The chamber grows one line at a time, so its top line always holds a rock.
+fn get_max_height(rested_rocks: &Grid<bool>) -> usize {
+    rested_rocks.height() - 1
+}
+
+// Tells whether the character was a jet.
+fn push_wind(winds: &mut Vec<Wind>, char: u8) -> bool {
+    match char_to_wind(char as char) {
+        Some(wind) => {
+            winds.push(wind);
+            true
+        }
+        None => false,
+    }
+}
+
+fn char_to_wind(char: char) -> Option<Wind> {
+    match char {
+        '<' => Some(Wind::Left),
+        '>' => Some(Wind::Right),
+        _ => None,
+    }
+}
+
+fn get_new_rock_origin(rested_rocks: &Grid<bool>) -> Point<usize> {
+    Point::new(3, 4 + get_max_height(rested_rocks))
+}
+
+fn get_new_rock(new_rock_origin: Point<usize>, rock_count: u64) -> Vec<Point<usize>> {
+    ROCKS[(rock_count % 5) as usize]
+        .iter()
+        .map(|&fragment| new_rock_origin + fragment)
+        .collect()
+}
+
+// The first and last columns are the walls, and the first line the floor.
+fn has_collision(rock: &[Point<usize>], rested_rocks: &Grid<bool>) -> bool {
+    rock.iter().any(|&rock_frag| {
+        rock_frag.x == 0
+            || rock_frag.x == CHAMBER_WIDTH + 1
+            || rested_rocks.get(rock_frag) == Some(&true)
+    })
+}
+
+fn move_rock_bottom(rock: &[Point<usize>]) -> Vec<Point<usize>> {
+    rock.iter().map(|&rock_frag| rock_frag - BOTTOM).collect()
+}
+
+fn move_rock_left(rock: &[Point<usize>]) -> Vec<Point<usize>> {
+    rock.iter().map(|&rock_frag| rock_frag - LEFT).collect()
+}
+
+fn move_rock_right(rock: &[Point<usize>]) -> Vec<Point<usize>> {
+    rock.iter().map(|&rock_frag| rock_frag + RIGHT).collect()
+}
+
+// Draws the top of the chamber with the falling rock, as the puzzle does.
+fn render_rested_rocks(rested_rocks: &Grid<bool>, rock: &[Point<usize>]) -> String {
+    let max_y = max(
+        get_max_height(rested_rocks),
+        rock.iter().map(|fragment| fragment.y).max().unwrap_or(0),
+    );
+    let min_y = max_y.saturating_sub(VIEW_HEIGHT - 1).max(1);
+
+    let mut lines: Vec<String> = (min_y..=max_y)
+        .rev()
+        .map(|y| {
+            let row_content: String = (1..=CHAMBER_WIDTH)
+                .map(|x| {
+                    let position = Point::new(x, y);
+                    if rested_rocks.get(position) == Some(&true) {
+                        '#'
+                    } else if rock.contains(&position) {
+                        '@'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            format!("|{row_content}|")
+        })
+        .collect();
+    if min_y == 1 {
+        lines.push(String::from("+-------+"));
+    }
+
+    lines.join("\n")
+}
@@ -0,0 +1,27 @@
+use common::animation::record;
+use day17::Day17;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn animation_shows_the_first_rock_being_pushed() {
+    let frames = record::<Day17>(EXAMPLE, 2).unwrap();
+
+    assert_eq!(
+        frames,
+        [
+            "\
+|..@@@@.|
+|.......|
+|.......|
+|.......|
++-------+",
+            "\
+|...@@@@|
+|.......|
+|.......|
+|.......|
++-------+",
+        ]
+    );
+}
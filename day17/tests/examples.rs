@@ -0,0 +1,22 @@
+use common::{solve, Part};
+use day17::Day17;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day17>(EXAMPLE, Part::One).unwrap(), "3068");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day17>(EXAMPLE, Part::Two).unwrap(), "1514285714288");
+}
+
+#[test]
+fn part_two_one_jet_pattern() {
+    // The jet pushes every rock against the right wall, leaving the first
+    // column empty all the way down.
+    assert_eq!(solve::<Day17>(">\n", Part::One).unwrap(), "5256");
+    assert_eq!(solve::<Day17>(">\n", Part::Two).unwrap(), "2600000000000");
+}
@@ -0,0 +1,25 @@
+use common::generator;
+use common::test_support::assert_stream_matches;
+use common::Solver;
+use day17::Day17;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn streaming_the_example_gives_the_same_answers() {
+    assert_stream_matches::<Day17>(EXAMPLE);
+}
+
+#[test]
+fn streaming_generated_inputs_gives_the_same_answers() {
+    for seed in 0..10 {
+        assert_stream_matches::<Day17>(&generator::generate::<Day17>(seed, Some(200)));
+    }
+}
+
+#[test]
+fn streaming_reports_the_same_parse_error() {
+    let input = ">><< >\n";
+    assert!(Day17::parse(input).is_err());
+    assert_stream_matches::<Day17>(input);
+}
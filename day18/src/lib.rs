@@ -0,0 +1,178 @@
+use common::error::parse_number;
+use common::search::reachable;
+use common::{Generator, ParseError, Point3, Random, SolveError, Solver};
+use std::collections::HashSet;
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    type Input = Vec<Point3<i32>>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<Point3<i32>>, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(line_index, line)| {
+                let coordinates = line
+                    .split(',')
+                    .map(|coordinate| parse_number::<i32>(line_index, line, coordinate))
+                    .collect::<Result<Vec<i32>, ParseError>>()?;
+
+                match coordinates[..] {
+                    [x, y, z] => Ok(Point3::new(x, y, z)),
+                    _ => Err(ParseError::at(line_index, 0, "`<x>,<y>,<z>`")),
+                }
+            })
+            .collect()
+    }
+
+    fn part_one(cubes: &Vec<Point3<i32>>) -> Result<usize, SolveError> {
+        let cubes = widen(cubes);
+
+        Ok(cubes
+            .iter()
+            .flat_map(|cube| cube.neighbours())
+            .filter(|neighbour| !cubes.contains(neighbour))
+            .count())
+    }
+
+    // The air of a pocket is walled in by a single droplet of cubes touching by
+    // a face, an edge or a corner, so each droplet floods the air around it in
+    // its own box. A droplet lying in a pocket of another one is left out.
+    fn part_two(cubes: &Vec<Point3<i32>>) -> Result<usize, SolveError> {
+        let droplets: Vec<Droplet> = get_droplets(&widen(cubes))
+            .into_iter()
+            .map(Droplet::new)
+            .collect();
+
+        Ok(droplets
+            .iter()
+            .filter(|droplet| {
+                let cube = *droplet.cubes.iter().next().unwrap();
+                !droplets.iter().any(|other| other.encloses(cube))
+            })
+            .map(Droplet::get_outside_area)
+            .sum())
+    }
+}
+
+// The size is the number of cubes, scattered in a box holding about four times
+// as many, like the real droplet.
+impl Generator for Day18 {
+    const DEFAULT_SIZE: usize = 2800;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        let side = ((4 * size) as f64).cbrt().ceil().max(1.) as i64;
+        let size = size.min((side * side * side) as usize);
+
+        let mut cubes: HashSet<Point3<i64>> = HashSet::new();
+        let mut lines = String::new();
+        while cubes.len() < size {
+            let cube = Point3::new(
+                random.between(0, side - 1),
+                random.between(0, side - 1),
+                random.between(0, side - 1),
+            );
+            if cubes.insert(cube) {
+                lines.push_str(&format!("{},{},{}\n", cube.x, cube.y, cube.z));
+            }
+        }
+
+        lines
+    }
+}
+
+// A droplet with the air flooded around it, inside a box one cube larger than
+// it on every side so that the air goes all around it. The pockets of air
+// trapped inside are never reached.
+struct Droplet {
+    cubes: HashSet<Point3<i64>>,
+    min_corner: Point3<i64>,
+    max_corner: Point3<i64>,
+    outside_air: HashSet<Point3<i64>>,
+}
+
+impl Droplet {
+    fn new(cubes: HashSet<Point3<i64>>) -> Droplet {
+        let min_corner = get_corner(&cubes, i64::min) - Point3::new(1, 1, 1);
+        let max_corner = get_corner(&cubes, i64::max) + Point3::new(1, 1, 1);
+        let mut droplet = Droplet {
+            cubes,
+            min_corner,
+            max_corner,
+            outside_air: HashSet::new(),
+        };
+
+        droplet.outside_air = reachable(min_corner, |air| {
+            air.neighbours()
+                .filter(|neighbour| {
+                    droplet.is_in_box(neighbour) && !droplet.cubes.contains(neighbour)
+                })
+                .collect::<Vec<Point3<i64>>>()
+        });
+        droplet
+    }
+
+    fn is_in_box(&self, point: &Point3<i64>) -> bool {
+        (self.min_corner.x..=self.max_corner.x).contains(&point.x)
+            && (self.min_corner.y..=self.max_corner.y).contains(&point.y)
+            && (self.min_corner.z..=self.max_corner.z).contains(&point.z)
+    }
+
+    // Whether the point is in a pocket of air of this droplet.
+    fn encloses(&self, point: Point3<i64>) -> bool {
+        self.is_in_box(&point) && !self.cubes.contains(&point) && !self.outside_air.contains(&point)
+    }
+
+    fn get_outside_area(&self) -> usize {
+        self.cubes
+            .iter()
+            .flat_map(|cube| cube.neighbours())
+            .filter(|neighbour| self.outside_air.contains(neighbour))
+            .count()
+    }
+}
+
+// The coordinates are widened so that the box around a cube at the edge of the
+// range of `i32` still fits.
+fn widen(cubes: &[Point3<i32>]) -> HashSet<Point3<i64>> {
+    cubes.iter().map(|cube| cube.cast()).collect()
+}
+
+// The groups of cubes touching by a face, an edge or a corner.
+fn get_droplets(cubes: &HashSet<Point3<i64>>) -> Vec<HashSet<Point3<i64>>> {
+    let mut remaining_cubes = cubes.clone();
+    let mut droplets: Vec<HashSet<Point3<i64>>> = Vec::new();
+
+    while let Some(&cube) = remaining_cubes.iter().next() {
+        let droplet = reachable(cube, |cube| {
+            cube.neighbours_with_diagonals()
+                .filter(|neighbour| cubes.contains(neighbour))
+                .collect::<Vec<Point3<i64>>>()
+        });
+        for cube in droplet.iter() {
+            remaining_cubes.remove(cube);
+        }
+        droplets.push(droplet);
+    }
+
+    droplets
+}
+
+fn get_corner(cubes: &HashSet<Point3<i64>>, select: impl Fn(i64, i64) -> i64) -> Point3<i64> {
+    cubes
+        .iter()
+        .copied()
+        .reduce(|corner, cube| {
+            Point3::new(
+                select(corner.x, cube.x),
+                select(corner.y, cube.y),
+                select(corner.z, cube.z),
+            )
+        })
+        .unwrap()
+}
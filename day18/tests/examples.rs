@@ -0,0 +1,42 @@
+use common::{solve, Part};
+use day18::Day18;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day18>(EXAMPLE, Part::One).unwrap(), "64");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day18>(EXAMPLE, Part::Two).unwrap(), "58");
+}
+
+#[test]
+fn cubes_at_the_edge_of_the_coordinates() {
+    let input = "-2147483648,0,0\n2147483647,0,0\n";
+    assert_eq!(solve::<Day18>(input, Part::One).unwrap(), "12");
+    assert_eq!(solve::<Day18>(input, Part::Two).unwrap(), "12");
+}
+
+#[test]
+fn droplet_inside_the_pocket_of_another() {
+    // A hollow cube of side 5 with a single cube in the middle of its pocket.
+    let mut input = String::from("2,2,2\n");
+    for x in 0..5 {
+        for y in 0..5 {
+            for z in 0..5 {
+                if [x, y, z]
+                    .iter()
+                    .any(|&coordinate| coordinate == 0 || coordinate == 4)
+                {
+                    input.push_str(&format!("{x},{y},{z}\n"));
+                }
+            }
+        }
+    }
+
+    assert_eq!(solve::<Day18>(&input, Part::One).unwrap(), "210");
+    assert_eq!(solve::<Day18>(&input, Part::Two).unwrap(), "150");
+}
@@ -0,0 +1,201 @@
+use common::error::parse_number;
+use common::{Generator, ParseError, Random, SolveError, Solver};
+use regex::Regex;
+
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+const MINERAL_COUNT: usize = 4;
+
+const MAX_MINUTE_ALONE: u32 = 24;
+const MAX_MINUTE_HUNGRY: u32 = 32;
+const HUNGRY_BLUEPRINT_COUNT: usize = 3;
+
+/// What each robot costs, by robot and then by mineral.
+#[derive(Debug)]
+pub struct Blueprint {
+    id: u32,
+    robot_costs: [[u32; MINERAL_COUNT]; MINERAL_COUNT],
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    minerals: [u32; MINERAL_COUNT],
+    robots: [u32; MINERAL_COUNT],
+    remaining_minute: u32,
+}
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    type Input = Vec<Blueprint>;
+    type AnswerOne = u64;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+        let parser_regex = Regex::new(
+            r"^Blueprint (\d+): Each ore robot costs (\d+) ore\. Each clay robot costs (\d+) ore\. Each obsidian robot costs (\d+) ore and (\d+) clay\. Each geode robot costs (\d+) ore and (\d+) obsidian\.$",
+        )
+        .unwrap();
+
+        input
+            .lines()
+            .enumerate()
+            .map(|(line_index, line)| {
+                let regex_captures = parser_regex.captures(line).ok_or_else(|| {
+                    ParseError::at(
+                        line_index,
+                        0,
+                        "`Blueprint <id>: Each ore robot costs <ore> ore. Each clay robot costs <ore> ore. Each obsidian robot costs <ore> ore and <clay> clay. Each geode robot costs <ore> ore and <obsidian> obsidian.`",
+                    )
+                })?;
+                let parse_capture =
+                    |group: usize| parse_number::<u32>(line_index, line, &regex_captures[group]);
+
+                let mut robot_costs = [[0; MINERAL_COUNT]; MINERAL_COUNT];
+                robot_costs[ORE][ORE] = parse_capture(2)?;
+                robot_costs[CLAY][ORE] = parse_capture(3)?;
+                robot_costs[OBSIDIAN][ORE] = parse_capture(4)?;
+                robot_costs[OBSIDIAN][CLAY] = parse_capture(5)?;
+                robot_costs[GEODE][ORE] = parse_capture(6)?;
+                robot_costs[GEODE][OBSIDIAN] = parse_capture(7)?;
+
+                Ok(Blueprint {
+                    id: parse_capture(1)?,
+                    robot_costs,
+                })
+            })
+            .collect()
+    }
+
+    // The quality levels are widened, as an id can be as large as a `u32`.
+    fn part_one(blueprints: &Vec<Blueprint>) -> Result<u64, SolveError> {
+        Ok(blueprints
+            .iter()
+            .map(|blueprint| {
+                blueprint.id as u64 * get_max_geodes(blueprint, MAX_MINUTE_ALONE) as u64
+            })
+            .sum())
+    }
+
+    fn part_two(blueprints: &Vec<Blueprint>) -> Result<u32, SolveError> {
+        Ok(blueprints
+            .iter()
+            .take(HUNGRY_BLUEPRINT_COUNT)
+            .map(|blueprint| get_max_geodes(blueprint, MAX_MINUTE_HUNGRY))
+            .product())
+    }
+}
+
+// The size is the number of blueprints, whose robots cost as much as in the
+// real ones.
+impl Generator for Day19 {
+    const DEFAULT_SIZE: usize = 30;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        (1..=size)
+            .map(|id| {
+                format!(
+                    "Blueprint {id}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                    random.between(2, 4),
+                    random.between(2, 4),
+                    random.between(2, 4),
+                    random.between(5, 20),
+                    random.between(2, 4),
+                    random.between(7, 20),
+                )
+            })
+            .collect()
+    }
+}
+
+fn get_max_geodes(blueprint: &Blueprint, max_minute: u32) -> u32 {
+    // Only one robot is built per minute, so there is no use in collecting
+    // more of a mineral per minute than the most any robot costs of it.
+    let mut max_robots = [u32::MAX; MINERAL_COUNT];
+    for mineral in [ORE, CLAY, OBSIDIAN] {
+        max_robots[mineral] = blueprint
+            .robot_costs
+            .iter()
+            .map(|robot_cost| robot_cost[mineral])
+            .max()
+            .unwrap();
+    }
+
+    let mut initial_robots = [0; MINERAL_COUNT];
+    initial_robots[ORE] = 1;
+    let initial_state = State {
+        minerals: [0; MINERAL_COUNT],
+        robots: initial_robots,
+        remaining_minute: max_minute,
+    };
+
+    let mut max_geodes = 0;
+    explore(blueprint, &max_robots, initial_state, &mut max_geodes);
+    max_geodes
+}
+
+// Rather than deciding what to do every minute, decides which robot to build
+// next and waits until it can be built.
+fn explore(
+    blueprint: &Blueprint,
+    max_robots: &[u32; MINERAL_COUNT],
+    state: State,
+    max_geodes: &mut u32,
+) {
+    let final_geodes = state.minerals[GEODE] + state.robots[GEODE] * state.remaining_minute;
+    *max_geodes = (*max_geodes).max(final_geodes);
+
+    if final_geodes + get_remaining_geode_upper_value(state.remaining_minute) <= *max_geodes {
+        return;
+    }
+
+    for robot in [GEODE, OBSIDIAN, CLAY, ORE] {
+        if state.robots[robot] >= max_robots[robot] {
+            continue;
+        }
+        if let Some(next_state) = get_next_state(blueprint, state, robot) {
+            explore(blueprint, max_robots, next_state, max_geodes);
+        }
+    }
+}
+
+fn get_next_state(blueprint: &Blueprint, state: State, robot: usize) -> Option<State> {
+    let robot_cost = &blueprint.robot_costs[robot];
+
+    let mut waiting_minute = 0;
+    for (mineral, &cost) in robot_cost.iter().enumerate() {
+        let missing_mineral = cost.saturating_sub(state.minerals[mineral]);
+        if missing_mineral == 0 {
+            continue;
+        }
+        if state.robots[mineral] == 0 {
+            return None;
+        }
+        waiting_minute = waiting_minute.max(missing_mineral.div_ceil(state.robots[mineral]));
+    }
+
+    // A robot built during the last minute collects nothing.
+    let spent_minute = waiting_minute + 1;
+    if spent_minute >= state.remaining_minute {
+        return None;
+    }
+
+    let mut next_state = state;
+    for (mineral, &cost) in robot_cost.iter().enumerate() {
+        next_state.minerals[mineral] += state.robots[mineral] * spent_minute;
+        next_state.minerals[mineral] -= cost;
+    }
+    next_state.robots[robot] += 1;
+    next_state.remaining_minute -= spent_minute;
+    Some(next_state)
+}
+
+// Even if a geode robot was built every minute left, they would not open more
+// geodes than this on top of the robots already built.
+fn get_remaining_geode_upper_value(remaining_minute: u32) -> u32 {
+    remaining_minute * remaining_minute.saturating_sub(1) / 2
+}
@@ -0,0 +1,20 @@
+use common::{solve, Part};
+use day19::Day19;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day19>(EXAMPLE, Part::One).unwrap(), "33");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day19>(EXAMPLE, Part::Two).unwrap(), "3472");
+}
+
+#[test]
+fn quality_level_of_the_largest_id() {
+    let input = "Blueprint 4294967295: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.\n";
+    assert_eq!(solve::<Day19>(input, Part::One).unwrap(), "51539607540");
+}
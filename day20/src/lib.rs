@@ -0,0 +1,214 @@
+use common::error::parse_number;
+use common::{Generator, ParseError, Random, SolveError, Solver};
+
+const DECRYPTION_KEY: i64 = 811_589_153;
+const MIXING_ROUND_COUNT: usize = 10;
+const GROVE_COORDINATE_OFFSETS: [usize; 3] = [1000, 2000, 3000];
+// The largest magnitude of a number, so that the sum of three decrypted ones
+// still fits in an `i64`.
+const MAX_NUMBER: i64 = i64::MAX / DECRYPTION_KEY / GROVE_COORDINATE_OFFSETS.len() as i64;
+
+/// The order of the numbers being mixed, as indexes in the input. They are
+/// split into buckets of about the square root of their count, so that moving
+/// a number takes about that long rather than their count. A bucket growing
+/// too large is split, and one growing too small is merged with its neighbour.
+struct MixedList {
+    buckets: Vec<Vec<usize>>,
+    bucket_indexes: Vec<usize>,
+    min_bucket_size: usize,
+    max_bucket_size: usize,
+}
+
+impl MixedList {
+    fn new(count: usize) -> MixedList {
+        let bucket_size = (count as f64).sqrt().ceil().max(1.0) as usize;
+        let buckets: Vec<Vec<usize>> = (0..count)
+            .collect::<Vec<usize>>()
+            .chunks(bucket_size)
+            .map(|bucket| bucket.to_vec())
+            .collect();
+        let bucket_indexes = (0..count).map(|index| index / bucket_size).collect();
+
+        MixedList {
+            buckets,
+            bucket_indexes,
+            min_bucket_size: bucket_size / 2,
+            max_bucket_size: 2 * bucket_size,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.bucket_indexes.len()
+    }
+
+    // Moves a number forward by `offset`, or backward when it is negative. The
+    // number is out of the list while moving, so the list it goes around is one
+    // number shorter.
+    fn move_number(&mut self, number_index: usize, offset: i64) {
+        let bucket_index = self.bucket_indexes[number_index];
+        let index_in_bucket = self.buckets[bucket_index]
+            .iter()
+            .position(|&index| index == number_index)
+            .unwrap();
+        let position: usize = self.buckets[..bucket_index]
+            .iter()
+            .map(Vec::len)
+            .sum::<usize>()
+            + index_in_bucket;
+
+        self.buckets[bucket_index].remove(index_in_bucket);
+        if self.buckets[bucket_index].len() < self.min_bucket_size && self.buckets.len() > 1 {
+            self.merge_bucket(bucket_index);
+        }
+
+        let other_count = self.len() as i64 - 1;
+        let new_position =
+            (position as i64 + offset.rem_euclid(other_count)).rem_euclid(other_count) as usize;
+        self.insert(new_position, number_index);
+    }
+
+    fn insert(&mut self, mut position: usize, number_index: usize) {
+        let mut bucket_index = 0;
+        while bucket_index + 1 < self.buckets.len() && position > self.buckets[bucket_index].len() {
+            position -= self.buckets[bucket_index].len();
+            bucket_index += 1;
+        }
+
+        self.buckets[bucket_index].insert(position, number_index);
+        self.bucket_indexes[number_index] = bucket_index;
+
+        if self.buckets[bucket_index].len() > self.max_bucket_size {
+            self.split_bucket(bucket_index);
+        }
+    }
+
+    fn split_bucket(&mut self, bucket_index: usize) {
+        let bucket = &mut self.buckets[bucket_index];
+        let second_half = bucket.split_off(bucket.len() / 2);
+        self.buckets.insert(bucket_index + 1, second_half);
+        self.update_bucket_indexes(bucket_index + 1);
+    }
+
+    // Merges the bucket with the next one, or with the previous one for the
+    // last bucket, splitting them again when they make too large a bucket.
+    fn merge_bucket(&mut self, bucket_index: usize) {
+        let first_bucket_index = bucket_index.min(self.buckets.len() - 2);
+        let second_bucket = self.buckets.remove(first_bucket_index + 1);
+        self.buckets[first_bucket_index].extend(second_bucket);
+        self.update_bucket_indexes(first_bucket_index);
+
+        if self.buckets[first_bucket_index].len() > self.max_bucket_size {
+            self.split_bucket(first_bucket_index);
+        }
+    }
+
+    fn update_bucket_indexes(&mut self, first_bucket_index: usize) {
+        for (bucket_index, bucket) in self.buckets.iter().enumerate().skip(first_bucket_index) {
+            for &number_index in bucket {
+                self.bucket_indexes[number_index] = bucket_index;
+            }
+        }
+    }
+
+    fn indexes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.buckets.iter().flatten().copied()
+    }
+}
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    type Input = Vec<i64>;
+    type AnswerOne = i64;
+    type AnswerTwo = i64;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        let numbers = input
+            .lines()
+            .enumerate()
+            .map(|(line_index, line)| {
+                let number = parse_number::<i64>(line_index, line, line)?;
+                if number.abs() > MAX_NUMBER {
+                    return Err(ParseError::at(
+                        line_index,
+                        0,
+                        format!("a number between -{MAX_NUMBER} and {MAX_NUMBER}"),
+                    ));
+                }
+                Ok(number)
+            })
+            .collect::<Result<Vec<i64>, ParseError>>()?;
+
+        if !numbers.contains(&0) {
+            return Err(ParseError::at(numbers.len(), 0, "a zero among the numbers"));
+        }
+
+        Ok(numbers)
+    }
+
+    fn part_one(numbers: &Vec<i64>) -> Result<i64, SolveError> {
+        Ok(get_grove_coordinates_sum(numbers, 1))
+    }
+
+    fn part_two(numbers: &Vec<i64>) -> Result<i64, SolveError> {
+        let decrypted_numbers: Vec<i64> = numbers
+            .iter()
+            .map(|number| number * DECRYPTION_KEY)
+            .collect();
+
+        Ok(get_grove_coordinates_sum(
+            &decrypted_numbers,
+            MIXING_ROUND_COUNT,
+        ))
+    }
+}
+
+// The size is the number of numbers, which hold a single 0.
+impl Generator for Day20 {
+    const DEFAULT_SIZE: usize = 5000;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        let size = size.max(1);
+        let zero_index = random.below(size);
+
+        (0..size)
+            .map(|index| {
+                let number = if index == zero_index {
+                    0
+                } else {
+                    match random.between(-10000, 9999) {
+                        0 => 10000,
+                        number => number,
+                    }
+                };
+                format!("{number}\n")
+            })
+            .collect()
+    }
+}
+
+fn get_grove_coordinates_sum(numbers: &[i64], mixing_round_count: usize) -> i64 {
+    let mut mixed_list = MixedList::new(numbers.len());
+
+    // A single number goes nowhere.
+    if numbers.len() > 1 {
+        for _round_index in 0..mixing_round_count {
+            for (number_index, &number) in numbers.iter().enumerate() {
+                mixed_list.move_number(number_index, number);
+            }
+        }
+    }
+
+    let mixed_numbers: Vec<i64> = mixed_list.indexes().map(|index| numbers[index]).collect();
+    let zero_position = mixed_numbers
+        .iter()
+        .position(|&number| number == 0)
+        .unwrap();
+
+    GROVE_COORDINATE_OFFSETS
+        .iter()
+        .map(|offset| mixed_numbers[(zero_position + offset) % mixed_numbers.len()])
+        .sum()
+}
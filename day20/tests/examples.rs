@@ -0,0 +1,25 @@
+use common::{solve, Part};
+use day20::Day20;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day20>(EXAMPLE, Part::One).unwrap(), "3");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day20>(EXAMPLE, Part::Two).unwrap(), "1623178306");
+}
+
+#[test]
+fn numbers_too_large_to_decrypt_are_invalid() {
+    assert!(solve::<Day20>("0\n3788194228\n", Part::One).is_ok());
+    assert_eq!(
+        solve::<Day20>("0\n3788194229\n", Part::One)
+            .unwrap_err()
+            .to_string(),
+        "Invalid input, line 2, column 1: expected a number between -3788194228 and 3788194228."
+    );
+}
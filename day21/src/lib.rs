@@ -0,0 +1,437 @@
+use common::error::{column_of, parse_number};
+use common::{Generator, Operator, ParseError, Random, SolveError, Solver};
+use std::collections::{HashMap, HashSet};
+
+const ROOT_MONKEY: &str = "root";
+const HUMAN: &str = "humn";
+// Keeps the generated numbers far from overflowing, whatever the human yells.
+const MAX_GENERATED_NUMBER: i64 = 10_000_000_000_000;
+
+#[derive(Debug, Clone)]
+pub struct Operation {
+    left_member: String,
+    operator: Operator,
+    right_member: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Job {
+    Number(i64),
+    Operation(Operation),
+}
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day21;
+
+impl Solver for Day21 {
+    type Input = HashMap<String, Job>;
+    type AnswerOne = i64;
+    type AnswerTwo = i64;
+
+    fn parse(input: &str) -> Result<HashMap<String, Job>, ParseError> {
+        let mut jobs: HashMap<String, Job> = HashMap::new();
+        let mut awaited_monkeys: Vec<(usize, usize, &str)> = Vec::new();
+
+        for (line_index, line) in input.lines().enumerate() {
+            let (monkey_name, job) = line
+                .split_once(": ")
+                .ok_or_else(|| ParseError::at(line_index, 0, "`<name>: <job>`"))?;
+
+            let job = match job.split(' ').collect::<Vec<&str>>()[..] {
+                [left_member, operator, right_member] => {
+                    for member in [left_member, right_member] {
+                        awaited_monkeys.push((line_index, column_of(line, member), member));
+                    }
+                    Job::Operation(Operation {
+                        left_member: String::from(left_member),
+                        operator: parse_operator(line_index, line, operator)?,
+                        right_member: String::from(right_member),
+                    })
+                }
+                _ => Job::Number(parse_number::<i64>(line_index, line, job)?),
+            };
+
+            if jobs.insert(String::from(monkey_name), job).is_some() {
+                return Err(ParseError::at(line_index, 0, "a monkey not named yet"));
+            }
+        }
+
+        for required_monkey in [ROOT_MONKEY, HUMAN] {
+            if !jobs.contains_key(required_monkey) {
+                return Err(ParseError::at(
+                    input.lines().count(),
+                    0,
+                    format!("a monkey named `{required_monkey}`"),
+                ));
+            }
+        }
+        if !matches!(jobs[ROOT_MONKEY], Job::Operation(_)) {
+            let line_index = input
+                .lines()
+                .position(|line| line.starts_with("root: "))
+                .unwrap();
+            return Err(ParseError::at(line_index, 6, "an operation"));
+        }
+
+        for (line_index, column_index, awaited_monkey) in awaited_monkeys.into_iter() {
+            if !jobs.contains_key(awaited_monkey) {
+                return Err(ParseError::at(
+                    line_index,
+                    column_index,
+                    "the name of a monkey",
+                ));
+            }
+        }
+
+        if has_cycle(&jobs) {
+            return Err(ParseError::at(
+                input.lines().count(),
+                0,
+                "monkeys not waiting on each other in a circle",
+            ));
+        }
+
+        Ok(jobs)
+    }
+
+    fn part_one(jobs: &HashMap<String, Job>) -> Result<i64, SolveError> {
+        evaluate(jobs, ROOT_MONKEY)
+    }
+
+    // The root monkey checks that both its members are equal: the member
+    // waiting on the human gives the number it must be equal to, then each
+    // operation on the way down to the human is inverted.
+    fn part_two(jobs: &HashMap<String, Job>) -> Result<i64, SolveError> {
+        let human_dependents = get_human_dependents(jobs);
+        let get_human_member = |monkey_name: &str, operation: &'_ Operation| match (
+            human_dependents.contains(operation.left_member.as_str()),
+            human_dependents.contains(operation.right_member.as_str()),
+        ) {
+            (true, false) => Ok(true),
+            (false, true) => Ok(false),
+            (true, true) => Err(SolveError::new(format!(
+                "both members of monkey `{monkey_name}` wait on the human"
+            ))),
+            (false, false) => Err(SolveError::new(format!(
+                "monkey `{monkey_name}` does not wait on the human"
+            ))),
+        };
+        let get_no_number_error = || {
+            SolveError::new(
+                "no number yelled by the human makes the members of the root monkey equal",
+            )
+        };
+
+        let Job::Operation(root_operation) = &jobs[ROOT_MONKEY] else {
+            unreachable!("the root monkey checks an operation");
+        };
+        let (mut current_monkey, mut expected_value) =
+            if get_human_member(ROOT_MONKEY, root_operation)? {
+                (
+                    root_operation.left_member.as_str(),
+                    evaluate(jobs, &root_operation.right_member)?,
+                )
+            } else {
+                (
+                    root_operation.right_member.as_str(),
+                    evaluate(jobs, &root_operation.left_member)?,
+                )
+            };
+
+        while current_monkey != HUMAN {
+            let Job::Operation(operation) = &jobs[current_monkey] else {
+                unreachable!("the monkeys waiting on the human work out operations");
+            };
+
+            if get_human_member(current_monkey, operation)? {
+                let right_value = evaluate(jobs, &operation.right_member)?;
+                expected_value = match operation.operator {
+                    Operator::Add => expected_value.checked_sub(right_value),
+                    Operator::Subtract => expected_value.checked_add(right_value),
+                    Operator::Multiply => divide_exactly(expected_value, right_value),
+                    Operator::Divide => expected_value.checked_mul(right_value),
+                }
+                .ok_or_else(get_no_number_error)?;
+                current_monkey = &operation.left_member;
+            } else {
+                let left_value = evaluate(jobs, &operation.left_member)?;
+                expected_value = match operation.operator {
+                    Operator::Add => expected_value.checked_sub(left_value),
+                    Operator::Subtract => left_value.checked_sub(expected_value),
+                    Operator::Multiply => divide_exactly(expected_value, left_value),
+                    // Any divisor larger than the dividend gives a zero.
+                    Operator::Divide if expected_value == 0 => left_value
+                        .checked_abs()
+                        .and_then(|value| value.checked_add(1)),
+                    Operator::Divide => left_value.checked_div(expected_value),
+                }
+                .ok_or_else(get_no_number_error)?;
+                current_monkey = &operation.right_member;
+            }
+        }
+
+        // The integer divisions only give a candidate, which may round to
+        // another number once yelled.
+        let mut human_jobs = jobs.clone();
+        human_jobs.insert(String::from(HUMAN), Job::Number(expected_value));
+        if evaluate(&human_jobs, &root_operation.left_member)?
+            != evaluate(&human_jobs, &root_operation.right_member)?
+        {
+            return Err(get_no_number_error());
+        }
+
+        Ok(expected_value)
+    }
+}
+
+// The size is about the number of monkeys. The human is at the bottom of a
+// chain of operations, each one with a number worked out by a tree of other
+// monkeys, and the root monkey compares the top of the chain with the number
+// of another tree, which the human makes equal by yelling a planted number.
+impl Generator for Day21 {
+    const DEFAULT_SIZE: usize = 2000;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        let chain_length = (size / 30).clamp(1, 70);
+        let tree_monkey_count = size.saturating_sub(chain_length + 2) / (chain_length + 1);
+        let mut monkey_names: HashSet<String> =
+            HashSet::from([String::from(ROOT_MONKEY), String::from(HUMAN)]);
+        let mut jobs: Vec<String> = Vec::new();
+
+        // The chain is worked out both for the planted number and for the
+        // number the human yells in the first part.
+        let human_number = random.between(1, 5000);
+        jobs.push(format!("{HUMAN}: {human_number}"));
+        let mut chain_values = [random.between(1, 1_000_000), human_number];
+        let mut chain_monkey = String::from(HUMAN);
+        for _ in 0..chain_length {
+            let can_multiply = chain_values
+                .iter()
+                .all(|value| value.abs() <= MAX_GENERATED_NUMBER / 5);
+            let (operator, member_value) = match random.below(if can_multiply { 3 } else { 2 }) {
+                0 => ('+', random.between(1, 1000)),
+                1 => ('-', random.between(1, 1000)),
+                _ => ('*', random.between(2, 5)),
+            };
+            let is_human_left = random.chance(0.5);
+            for value in chain_values.iter_mut() {
+                *value = match (operator, is_human_left) {
+                    ('+', _) => *value + member_value,
+                    ('-', true) => *value - member_value,
+                    ('-', false) => member_value - *value,
+                    _ => *value * member_value,
+                };
+            }
+
+            let member = write_number_tree(
+                random,
+                &mut jobs,
+                &mut monkey_names,
+                member_value,
+                tree_monkey_count,
+            );
+            let monkey = get_new_monkey_name(random, &mut monkey_names);
+            let (left_member, right_member) = if is_human_left {
+                (chain_monkey, member)
+            } else {
+                (member, chain_monkey)
+            };
+            jobs.push(format!("{monkey}: {left_member} {operator} {right_member}"));
+            chain_monkey = monkey;
+        }
+
+        let member = write_number_tree(
+            random,
+            &mut jobs,
+            &mut monkey_names,
+            chain_values[0],
+            tree_monkey_count,
+        );
+        if random.chance(0.5) {
+            jobs.push(format!("{ROOT_MONKEY}: {chain_monkey} + {member}"));
+        } else {
+            jobs.push(format!("{ROOT_MONKEY}: {member} + {chain_monkey}"));
+        }
+
+        random.shuffle(&mut jobs);
+        jobs.iter().map(|job| format!("{job}\n")).collect()
+    }
+}
+
+fn evaluate(jobs: &HashMap<String, Job>, monkey_name: &str) -> Result<i64, SolveError> {
+    match &jobs[monkey_name] {
+        Job::Number(number) => Ok(*number),
+        Job::Operation(operation) => {
+            let left_value = evaluate(jobs, &operation.left_member)?;
+            let right_value = evaluate(jobs, &operation.right_member)?;
+            operation
+                .operator
+                .checked_apply(left_value, right_value)
+                .ok_or_else(|| {
+                    SolveError::new(format!(
+                        "monkey `{monkey_name}` overflows or divides by zero"
+                    ))
+                })
+        }
+    }
+}
+
+// The quotient, when the division leaves no remainder.
+fn divide_exactly(dividend: i64, divisor: i64) -> Option<i64> {
+    match dividend.checked_rem(divisor)? {
+        0 => dividend.checked_div(divisor),
+        _ => None,
+    }
+}
+
+// The monkeys waiting on the human, directly or through other monkeys, found
+// once for every monkey rather than walking their members again for each.
+fn get_human_dependents(jobs: &HashMap<String, Job>) -> HashSet<&str> {
+    let mut is_dependent: HashMap<&str, bool> = HashMap::from([(HUMAN, true)]);
+
+    for start_monkey in jobs.keys() {
+        let mut monkey_stack: Vec<&str> = vec![start_monkey];
+        while let Some(&monkey_name) = monkey_stack.last() {
+            if is_dependent.contains_key(monkey_name) {
+                monkey_stack.pop();
+                continue;
+            }
+
+            match &jobs[monkey_name] {
+                Job::Number(_) => {
+                    is_dependent.insert(monkey_name, false);
+                    monkey_stack.pop();
+                }
+                Job::Operation(operation) => {
+                    let members = [&operation.left_member, &operation.right_member];
+                    let unchecked_members: Vec<&str> = members
+                        .iter()
+                        .map(|member| member.as_str())
+                        .filter(|member| !is_dependent.contains_key(member))
+                        .collect();
+                    if unchecked_members.is_empty() {
+                        let dependent = members.iter().any(|member| is_dependent[member.as_str()]);
+                        is_dependent.insert(monkey_name, dependent);
+                        monkey_stack.pop();
+                    } else {
+                        monkey_stack.extend(unchecked_members);
+                    }
+                }
+            }
+        }
+    }
+
+    is_dependent
+        .into_iter()
+        .filter(|&(_, dependent)| dependent)
+        .map(|(monkey_name, _)| monkey_name)
+        .collect()
+}
+
+// Checks, from every monkey, whether the monkeys it waits on come back to it.
+// Monkeys known not to be in a cycle are not walked again.
+fn has_cycle(jobs: &HashMap<String, Job>) -> bool {
+    let mut checked_monkeys: HashMap<&str, bool> = HashMap::new();
+
+    for start_monkey in jobs.keys() {
+        let mut monkey_stack: Vec<(&str, bool)> = vec![(start_monkey.as_str(), false)];
+        while let Some((monkey_name, are_members_checked)) = monkey_stack.pop() {
+            if are_members_checked {
+                checked_monkeys.insert(monkey_name, true);
+                continue;
+            }
+            match checked_monkeys.get(monkey_name) {
+                Some(true) => continue,
+                Some(false) => return true,
+                None => {}
+            }
+
+            // Walking the members of the monkey, which is in a cycle if one of
+            // them comes back to it before it is checked.
+            checked_monkeys.insert(monkey_name, false);
+            monkey_stack.push((monkey_name, true));
+            if let Job::Operation(operation) = &jobs[monkey_name] {
+                for member in [&operation.left_member, &operation.right_member] {
+                    match checked_monkeys.get(member.as_str()) {
+                        Some(true) => {}
+                        Some(false) => return true,
+                        None => monkey_stack.push((member, false)),
+                    }
+                }
+            }
+        }
+    }
+
+    false
+}
+
+fn parse_operator(line_index: usize, line: &str, operator: &str) -> Result<Operator, ParseError> {
+    Operator::from_symbol(operator).ok_or_else(|| {
+        ParseError::at(
+            line_index,
+            column_of(line, operator),
+            "`+`, `-`, `*` or `/`",
+        )
+    })
+}
+
+// Writes the jobs of about `monkey_count` monkeys working out the number, and
+// returns the name of the one yelling it. Every division is exact.
+fn write_number_tree(
+    random: &mut Random,
+    jobs: &mut Vec<String>,
+    monkey_names: &mut HashSet<String>,
+    number: i64,
+    monkey_count: usize,
+) -> String {
+    let monkey = get_new_monkey_name(random, monkey_names);
+
+    let mut operations: Vec<(char, i64, i64)> = Vec::new();
+    if monkey_count >= 3 {
+        if number >= 2 {
+            let left_number = random.between(1, number - 1);
+            operations.push(('+', left_number, number - left_number));
+        }
+        let right_number = random.between(1, 20);
+        if number.abs() <= MAX_GENERATED_NUMBER {
+            operations.push(('-', number + right_number, right_number));
+        }
+        let factor = random.between(2, 5);
+        if number != 0 && number % factor == 0 {
+            operations.push(('*', number / factor, factor));
+        }
+        if number.abs() <= MAX_GENERATED_NUMBER / factor {
+            operations.push(('/', number * factor, factor));
+        }
+    }
+    if operations.is_empty() {
+        jobs.push(format!("{monkey}: {number}"));
+        return monkey;
+    }
+
+    let &(operator, left_number, right_number) = random.choose(&operations);
+    let left_monkey_count = 1 + random.below(monkey_count - 2);
+    let left_member = write_number_tree(random, jobs, monkey_names, left_number, left_monkey_count);
+    let right_member = write_number_tree(
+        random,
+        jobs,
+        monkey_names,
+        right_number,
+        monkey_count - 1 - left_monkey_count,
+    );
+    jobs.push(format!("{monkey}: {left_member} {operator} {right_member}"));
+
+    monkey
+}
+
+fn get_new_monkey_name(random: &mut Random, monkey_names: &mut HashSet<String>) -> String {
+    loop {
+        let monkey_name: String = (0..4)
+            .map(|_| (b'a' + random.below(26) as u8) as char)
+            .collect();
+        if monkey_names.insert(monkey_name.clone()) {
+            return monkey_name;
+        }
+    }
+}
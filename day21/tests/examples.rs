@@ -0,0 +1,72 @@
+use common::{solve, Error, Part, SolveError};
+use day21::Day21;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day21>(EXAMPLE, Part::One).unwrap(), "152");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day21>(EXAMPLE, Part::Two).unwrap(), "301");
+}
+
+#[test]
+fn root_not_waiting_on_the_human() {
+    let input = "root: a + b\na: 5\nb: 3\nhumn: 3\n";
+    assert_eq!(solve::<Day21>(input, Part::One).unwrap(), "8");
+    assert_eq!(
+        solve::<Day21>(input, Part::Two),
+        Err(Error::Solve(SolveError::new(
+            "monkey `root` does not wait on the human"
+        )))
+    );
+}
+
+#[test]
+fn division_by_zero() {
+    let input = "root: a / b\na: humn + humn\nb: 0\nhumn: 3\n";
+    assert_eq!(
+        solve::<Day21>(input, Part::One),
+        Err(Error::Solve(SolveError::new(
+            "monkey `root` overflows or divides by zero"
+        )))
+    );
+    assert_eq!(
+        solve::<Day21>(input, Part::Two),
+        Err(Error::Solve(SolveError::new(
+            "both members of monkey `a` wait on the human"
+        )))
+    );
+}
+
+#[test]
+fn no_number_for_the_human() {
+    let input = "root: a + b\na: humn * c\nb: 7\nc: 2\nhumn: 3\n";
+    assert_eq!(
+        solve::<Day21>(input, Part::Two),
+        Err(Error::Solve(SolveError::new(
+            "no number yelled by the human makes the members of the root monkey equal"
+        )))
+    );
+}
+
+#[test]
+fn human_divisor_rounding_to_another_number() {
+    // 11 / 2 is 5 and 11 / 3 is 3, so no divisor gives 4.
+    let input = "root: aaaa + bbbb\naaaa: cccc / humn\ncccc: 11\nbbbb: 4\nhumn: 1\n";
+    assert_eq!(
+        solve::<Day21>(input, Part::Two),
+        Err(Error::Solve(SolveError::new(
+            "no number yelled by the human makes the members of the root monkey equal"
+        )))
+    );
+}
+
+#[test]
+fn human_divisor_giving_zero() {
+    let input = "root: aaaa + bbbb\naaaa: cccc / humn\ncccc: 11\nbbbb: 0\nhumn: 1\n";
+    assert_eq!(solve::<Day21>(input, Part::Two).unwrap(), "12");
+}
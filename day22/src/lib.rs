@@ -0,0 +1,441 @@
+use common::error::{column_of, parse_number};
+use common::{Direction, Generator, Grid, ParseError, Point, Point3, Random, SolveError, Solver};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Void,
+    Open,
+    Wall,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Instruction {
+    Forward(u32),
+    TurnLeft,
+    TurnRight,
+}
+
+/// A face of the cube: where it is on the map, and how it is oriented once
+/// folded. The cube is centered on the origin, and each face is described by
+/// the direction its outside faces and the directions its lines and columns
+/// go to.
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    origin: Point<usize>,
+    normal: Point3<i32>,
+    right: Point3<i32>,
+    down: Point3<i32>,
+}
+
+#[derive(Debug)]
+struct Cube {
+    face_size: usize,
+    faces: Vec<Face>,
+    face_indexes: Grid<Option<usize>>,
+}
+
+#[derive(Debug)]
+pub struct Notes {
+    tiles: Grid<Tile>,
+    start_position: Point<usize>,
+    path: Vec<Instruction>,
+    cube: Cube,
+}
+
+type Walker = (Point<usize>, Direction);
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day22;
+
+impl Solver for Day22 {
+    type Input = Notes;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<Notes, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+        let Some(map_height) = lines.iter().position(|line| line.is_empty()) else {
+            return Err(ParseError::at(
+                lines.len(),
+                0,
+                "an empty line after the map",
+            ));
+        };
+        let map_width = lines[..map_height]
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        if map_height == 0 || map_width == 0 {
+            return Err(ParseError::at(0, 0, "a map"));
+        }
+
+        let mut tiles = Grid::new(map_height, map_width, Tile::Void);
+        for (line_index, line) in lines[..map_height].iter().enumerate() {
+            for (column_index, char) in line.chars().enumerate() {
+                tiles[Point::new(column_index, line_index)] = match char {
+                    ' ' => Tile::Void,
+                    '.' => Tile::Open,
+                    '#' => Tile::Wall,
+                    _ => return Err(ParseError::at(line_index, column_index, "` `, `.` or `#`")),
+                };
+            }
+        }
+
+        let Some(start_column) = tiles.line(0).iter().position(|&tile| tile == Tile::Open) else {
+            return Err(ParseError::at(0, 0, "an open tile on the first line"));
+        };
+
+        let path_line_index = map_height + 1;
+        let Some(path_line) = lines.get(path_line_index) else {
+            return Err(ParseError::at(path_line_index, 0, "a path"));
+        };
+        let path = parse_path(path_line_index, path_line)?;
+
+        let cube =
+            fold_cube(&tiles).ok_or_else(|| ParseError::at(0, 0, "a map folding into a cube"))?;
+
+        Ok(Notes {
+            tiles,
+            start_position: Point::new(start_column, 0),
+            path,
+            cube,
+        })
+    }
+
+    fn part_one(notes: &Notes) -> Result<usize, SolveError> {
+        Ok(follow_path(notes, |walker| {
+            wrap_on_map(&notes.tiles, walker)
+        }))
+    }
+
+    fn part_two(notes: &Notes) -> Result<usize, SolveError> {
+        Ok(follow_path(notes, |walker| {
+            wrap_on_cube(&notes.cube, walker)
+        }))
+    }
+}
+
+// The size is the side of the faces. The faces are laid out as a random net of
+// the cube, found by growing six squares until they fold, and the path goes
+// forty times around the side.
+impl Generator for Day22 {
+    const DEFAULT_SIZE: usize = 50;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        let face_size = size.max(1);
+        let slots = loop {
+            let slots = generate_net(random);
+            if fold_cube(&slots).is_some() {
+                break slots;
+            }
+        };
+
+        let mut lines: Vec<String> = Vec::new();
+        for y in 0..slots.height() * face_size {
+            let line_slots = slots.line(y / face_size);
+            let line_width = face_size
+                * (1 + line_slots
+                    .iter()
+                    .rposition(|&tile| tile == Tile::Open)
+                    .unwrap());
+            let line: String = (0..line_width)
+                .map(|x| match line_slots[x / face_size] {
+                    Tile::Open if random.chance(0.85) => '.',
+                    Tile::Open => '#',
+                    _ => ' ',
+                })
+                .collect();
+            lines.push(line);
+        }
+        // The path starts on the first open tile of the first line.
+        let start_column = lines[0].find(['.', '#']).unwrap();
+        lines[0].replace_range(start_column..start_column + 1, ".");
+
+        let mut path = random.between(1, 50).to_string();
+        for _ in 1..40 * face_size {
+            path.push(*random.choose(&['L', 'R']));
+            path.push_str(&random.between(1, 50).to_string());
+        }
+
+        format!("{}\n\n{path}\n", lines.join("\n"))
+    }
+}
+
+fn parse_path(line_index: usize, line: &str) -> Result<Vec<Instruction>, ParseError> {
+    let mut path: Vec<Instruction> = Vec::new();
+    let mut rest = line;
+
+    while !rest.is_empty() {
+        let digit_count = rest.chars().take_while(char::is_ascii_digit).count();
+        if digit_count > 0 {
+            let (steps, next_rest) = rest.split_at(digit_count);
+            path.push(Instruction::Forward(parse_number::<u32>(
+                line_index, line, steps,
+            )?));
+            rest = next_rest;
+            continue;
+        }
+
+        path.push(match rest.as_bytes()[0] {
+            b'L' => Instruction::TurnLeft,
+            b'R' => Instruction::TurnRight,
+            _ => {
+                return Err(ParseError::at(
+                    line_index,
+                    column_of(line, rest),
+                    "a number of steps, `L` or `R`",
+                ))
+            }
+        });
+        rest = &rest[1..];
+    }
+
+    if path.is_empty() {
+        return Err(ParseError::at(line_index, 0, "a path"));
+    }
+
+    Ok(path)
+}
+
+// Walks the path, `wrap` telling where a step off the edge of the map leads.
+fn follow_path(notes: &Notes, wrap: impl Fn(Walker) -> Walker) -> usize {
+    let mut position = notes.start_position;
+    let mut direction = Direction::Right;
+
+    for &instruction in notes.path.iter() {
+        match instruction {
+            Instruction::TurnLeft => direction = direction.turn_left(),
+            Instruction::TurnRight => direction = direction.turn_right(),
+            Instruction::Forward(steps) => {
+                for _step in 0..steps {
+                    let next_walker = match position.checked_step(direction) {
+                        Some(next_position)
+                            if notes.tiles.get(next_position).unwrap_or(&Tile::Void)
+                                != &Tile::Void =>
+                        {
+                            (next_position, direction)
+                        }
+                        _ => wrap((position, direction)),
+                    };
+                    if notes.tiles[next_walker.0] == Tile::Wall {
+                        break;
+                    }
+                    (position, direction) = next_walker;
+                }
+            }
+        }
+    }
+
+    get_password(position, direction)
+}
+
+fn get_password(position: Point<usize>, direction: Direction) -> usize {
+    let facing = match direction {
+        Direction::Right => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Up => 3,
+    };
+
+    1000 * (position.y + 1) + 4 * (position.x + 1) + facing
+}
+
+// Comes back from the other side of the map, in the same direction.
+fn wrap_on_map(tiles: &Grid<Tile>, (position, direction): Walker) -> Walker {
+    let mut next_position = position;
+    loop {
+        next_position = match direction {
+            Direction::Up => Point::new(
+                next_position.x,
+                (next_position.y + tiles.height() - 1) % tiles.height(),
+            ),
+            Direction::Down => Point::new(next_position.x, (next_position.y + 1) % tiles.height()),
+            Direction::Left => Point::new(
+                (next_position.x + tiles.width() - 1) % tiles.width(),
+                next_position.y,
+            ),
+            Direction::Right => Point::new((next_position.x + 1) % tiles.width(), next_position.y),
+        };
+        if tiles[next_position] != Tile::Void {
+            return (next_position, direction);
+        }
+    }
+}
+
+// Goes over the edge of the cube to the face on the other side. Positions are
+// those of the centers of the tiles on the cube, doubled to stay integers: a
+// face lies at `face_size` from the origin, and its tiles' centers span from
+// `1 - face_size` to `face_size - 1` on both its axes.
+fn wrap_on_cube(cube: &Cube, (position, direction): Walker) -> Walker {
+    let face_size = cube.face_size as i32;
+    let face = &cube.faces[get_face_index(cube, position)];
+    let local_position = Point::new(
+        (position.x - face.origin.x) as i32,
+        (position.y - face.origin.y) as i32,
+    );
+
+    let center = face.normal * face_size
+        + face.right * (2 * local_position.x + 1 - face_size)
+        + face.down * (2 * local_position.y + 1 - face_size);
+    // Half a tile to the edge, then half a tile down the next face.
+    let next_center = center + get_axis(face, direction) - face.normal;
+
+    let next_face = cube
+        .faces
+        .iter()
+        .find(|next_face| next_face.normal == get_axis(face, direction))
+        .unwrap();
+    let next_local_position = Point::new(
+        (dot(next_center, next_face.right) + face_size - 1) / 2,
+        (dot(next_center, next_face.down) + face_size - 1) / 2,
+    );
+    let next_direction = Direction::ALL
+        .into_iter()
+        .find(|&next_direction| get_axis(next_face, next_direction) == -face.normal)
+        .unwrap();
+
+    (
+        next_face.origin
+            + Point::new(
+                next_local_position.x as usize,
+                next_local_position.y as usize,
+            ),
+        next_direction,
+    )
+}
+
+fn get_face_index(cube: &Cube, position: Point<usize>) -> usize {
+    cube.face_indexes[Point::new(position.x / cube.face_size, position.y / cube.face_size)].unwrap()
+}
+
+fn get_axis(face: &Face, direction: Direction) -> Point3<i32> {
+    match direction {
+        Direction::Right => face.right,
+        Direction::Down => face.down,
+        Direction::Left => -face.right,
+        Direction::Up => -face.down,
+    }
+}
+
+fn dot(point_a: Point3<i32>, point_b: Point3<i32>) -> i32 {
+    point_a.x * point_b.x + point_a.y * point_b.y + point_a.z * point_b.z
+}
+
+// Cuts the map into square faces, and folds them from the first one: a face
+// next to another on the map is folded over their common edge, so that it faces
+// where the other one was going.
+fn fold_cube(tiles: &Grid<Tile>) -> Option<Cube> {
+    let tile_count = tiles
+        .points()
+        .filter(|&point| tiles[point] != Tile::Void)
+        .count();
+    let face_size =
+        (1..=tiles.width()).find(|face_size| 6 * face_size * face_size >= tile_count)?;
+    if 6 * face_size * face_size != tile_count {
+        return None;
+    }
+
+    let mut face_indexes: Grid<Option<usize>> =
+        Grid::new(tiles.height() / face_size, tiles.width() / face_size, None);
+    let mut faces: Vec<Face> = Vec::new();
+    let first_slot = face_indexes
+        .points()
+        .find(|&slot| tiles[slot * face_size] != Tile::Void)?;
+    let mut slot_stack: Vec<(Point<usize>, Face)> = vec![(
+        first_slot,
+        Face {
+            origin: first_slot * face_size,
+            normal: Point3::new(0, 0, 1),
+            right: Point3::new(1, 0, 0),
+            down: Point3::new(0, 1, 0),
+        },
+    )];
+
+    while let Some((slot, face)) = slot_stack.pop() {
+        if face_indexes[slot].is_some() {
+            continue;
+        }
+        if faces
+            .iter()
+            .any(|other_face| other_face.normal == face.normal)
+        {
+            return None;
+        }
+        face_indexes[slot] = Some(faces.len());
+        faces.push(face);
+
+        for direction in Direction::ALL {
+            let Some(next_slot) = slot.checked_step(direction) else {
+                continue;
+            };
+            if !face_indexes.contains(next_slot) || tiles[next_slot * face_size] == Tile::Void {
+                continue;
+            }
+            let next_normal = get_axis(&face, direction);
+            let next_face = match direction {
+                Direction::Right | Direction::Left => Face {
+                    origin: next_slot * face_size,
+                    normal: next_normal,
+                    right: match direction {
+                        Direction::Right => -face.normal,
+                        _ => face.normal,
+                    },
+                    down: face.down,
+                },
+                Direction::Down | Direction::Up => Face {
+                    origin: next_slot * face_size,
+                    normal: next_normal,
+                    right: face.right,
+                    down: match direction {
+                        Direction::Down => -face.normal,
+                        _ => face.normal,
+                    },
+                },
+            };
+            slot_stack.push((next_slot, next_face));
+        }
+    }
+
+    // Six faces of full squares hold every tile of the map.
+    let is_cube = faces.len() == 6
+        && faces.iter().all(|face| {
+            (0..face_size).all(|y| {
+                (0..face_size).all(|x| tiles[face.origin + Point::new(x, y)] != Tile::Void)
+            })
+        });
+    if !is_cube {
+        return None;
+    }
+
+    Some(Cube {
+        face_size,
+        faces,
+        face_indexes,
+    })
+}
+
+// Grows six squares side by side from a random one, and trims the grid around
+// them.
+fn generate_net(random: &mut Random) -> Grid<Tile> {
+    let mut slots: Vec<Point<usize>> = vec![Point::new(random.below(6), random.below(6))];
+    while slots.len() < 6 {
+        let slot = random
+            .choose(&slots)
+            .checked_step(*random.choose(&Direction::ALL));
+        if let Some(slot) = slot.filter(|slot| slot.x < 6 && slot.y < 6 && !slots.contains(slot)) {
+            slots.push(slot);
+        }
+    }
+
+    let min_x = slots.iter().map(|slot| slot.x).min().unwrap();
+    let min_y = slots.iter().map(|slot| slot.y).min().unwrap();
+    let max_x = slots.iter().map(|slot| slot.x).max().unwrap();
+    let max_y = slots.iter().map(|slot| slot.y).max().unwrap();
+    let mut net = Grid::new(max_y - min_y + 1, max_x - min_x + 1, Tile::Void);
+    for slot in slots {
+        net[slot - Point::new(min_x, min_y)] = Tile::Open;
+    }
+    net
+}
@@ -0,0 +1,14 @@
+use common::{solve, Part};
+use day22::Day22;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day22>(EXAMPLE, Part::One).unwrap(), "6032");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day22>(EXAMPLE, Part::Two).unwrap(), "5031");
+}
@@ -0,0 +1,270 @@
+use common::{
+    Animation, CompassDirection, Generator, Grid, Image, ParseError, Point, Random, Recording,
+    Render, Rgb, SolveError, Solver,
+};
+use std::collections::{HashMap, HashSet};
+
+const ROUND_COUNT: usize = 10;
+const TILE_SCALE: usize = 4;
+const MAX_FRAME_COUNT: usize = 150;
+const GROUND_COLOR: Rgb = Rgb(40, 90, 40);
+const ELF_COLOR: Rgb = Rgb(230, 200, 90);
+
+// Each direction an elf may propose to move to, with the directions that must
+// be free of elves for it to do so.
+const PROPOSALS: [(CompassDirection, [CompassDirection; 3]); 4] = [
+    (
+        CompassDirection::North,
+        [
+            CompassDirection::North,
+            CompassDirection::NorthEast,
+            CompassDirection::NorthWest,
+        ],
+    ),
+    (
+        CompassDirection::South,
+        [
+            CompassDirection::South,
+            CompassDirection::SouthEast,
+            CompassDirection::SouthWest,
+        ],
+    ),
+    (
+        CompassDirection::West,
+        [
+            CompassDirection::West,
+            CompassDirection::NorthWest,
+            CompassDirection::SouthWest,
+        ],
+    ),
+    (
+        CompassDirection::East,
+        [
+            CompassDirection::East,
+            CompassDirection::NorthEast,
+            CompassDirection::SouthEast,
+        ],
+    ),
+];
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day23;
+
+impl Solver for Day23 {
+    type Input = HashSet<Point<i32>>;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<HashSet<Point<i32>>, ParseError> {
+        let grove = Grid::parse(input, "`.` or `#`", |char| match char {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        Ok(grove
+            .points()
+            .filter(|&point| grove[point])
+            .map(|point| Point::new(point.x as i32, point.y as i32))
+            .collect())
+    }
+
+    fn part_one(elves: &HashSet<Point<i32>>) -> Result<usize, SolveError> {
+        let mut elves = elves.clone();
+        for round_index in 0..ROUND_COUNT {
+            elves = spread_elves(&elves, round_index).0;
+        }
+
+        let (min_corner, max_corner) = get_corners(&elves);
+        let area =
+            (max_corner.x - min_corner.x + 1) as usize * (max_corner.y - min_corner.y + 1) as usize;
+        Ok(area - elves.len())
+    }
+
+    fn part_two(elves: &HashSet<Point<i32>>) -> Result<usize, SolveError> {
+        let mut elves = elves.clone();
+        let mut round_index = 0;
+        loop {
+            let (next_elves, has_moved) = spread_elves(&elves, round_index);
+            round_index += 1;
+            if !has_moved {
+                return Ok(round_index);
+            }
+            elves = next_elves;
+        }
+    }
+}
+
+// The size is the side of the grove, where about half the tiles hold an elf.
+impl Generator for Day23 {
+    const DEFAULT_SIZE: usize = 70;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                (0..size.max(1))
+                    .map(|_| if random.chance(0.5) { '#' } else { '.' })
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect()
+    }
+}
+
+// The elf field after each round, until the elves stop moving.
+impl Animation for Day23 {
+    fn animate(elves: &HashSet<Point<i32>>, recording: &mut Recording) {
+        let mut elves = elves.clone();
+        recording.record(|| get_elf_field(&elves, get_corners(&elves)).render(get_tile));
+
+        for round_index in 0.. {
+            if recording.is_full() {
+                return;
+            }
+            let (next_elves, has_moved) = spread_elves(&elves, round_index);
+            if !has_moved {
+                return;
+            }
+            elves = next_elves;
+            recording.record(|| get_elf_field(&elves, get_corners(&elves)).render(get_tile));
+        }
+    }
+}
+
+// The elves spreading out until none of them moves, every frame showing the
+// area they end up covering.
+impl Render for Day23 {
+    fn render(elves: &HashSet<Point<i32>>) -> Vec<Image> {
+        let mut round_count: usize = 0;
+        let (mut min_corner, mut max_corner) = get_corners(elves);
+        spread_until_still(elves, |elves| {
+            round_count += 1;
+            let (round_min_corner, round_max_corner) = get_corners(elves);
+            min_corner = Point::new(
+                min_corner.x.min(round_min_corner.x),
+                min_corner.y.min(round_min_corner.y),
+            );
+            max_corner = Point::new(
+                max_corner.x.max(round_max_corner.x),
+                max_corner.y.max(round_max_corner.y),
+            );
+        });
+        let frame_step = round_count.div_ceil(MAX_FRAME_COUNT).max(1);
+
+        let draw_elves = |elves: &HashSet<Point<i32>>| {
+            let elf_field = get_elf_field(elves, (min_corner, max_corner));
+            Image::from_grid(&elf_field, TILE_SCALE, |&is_elf| {
+                if is_elf {
+                    ELF_COLOR
+                } else {
+                    GROUND_COLOR
+                }
+            })
+        };
+        let mut frames = vec![draw_elves(elves)];
+        let mut round_index = 0;
+        spread_until_still(elves, |elves| {
+            round_index += 1;
+            if round_index % frame_step == 0 || round_index == round_count {
+                frames.push(draw_elves(elves));
+            }
+        });
+
+        frames
+    }
+}
+
+// Plays rounds until the elves stop moving, showing them to `on_round` after
+// each round they moved in.
+fn spread_until_still(elves: &HashSet<Point<i32>>, mut on_round: impl FnMut(&HashSet<Point<i32>>)) {
+    let mut elves = elves.clone();
+    for round_index in 0.. {
+        let (next_elves, has_moved) = spread_elves(&elves, round_index);
+        if !has_moved {
+            return;
+        }
+        elves = next_elves;
+        on_round(&elves);
+    }
+}
+
+// Plays a round, in which the first direction proposed rotates, and tells
+// whether any elf moved.
+fn spread_elves(elves: &HashSet<Point<i32>>, round_index: usize) -> (HashSet<Point<i32>>, bool) {
+    let mut proposals: HashMap<Point<i32>, Point<i32>> = HashMap::new();
+    let mut proposal_counts: HashMap<Point<i32>, usize> = HashMap::new();
+
+    for &elf in elves.iter() {
+        let is_alone = elf
+            .neighbours_with_diagonals()
+            .all(|neighbour| !elves.contains(&neighbour));
+        if is_alone {
+            continue;
+        }
+
+        let proposal = (0..PROPOSALS.len())
+            .map(|proposal_index| PROPOSALS[(round_index + proposal_index) % PROPOSALS.len()])
+            .find(|(_, checked_directions)| {
+                checked_directions
+                    .iter()
+                    .all(|&checked_direction| !elves.contains(&elf.step(checked_direction)))
+            });
+        if let Some((direction, _)) = proposal {
+            let destination = elf.step(direction);
+            proposals.insert(elf, destination);
+            *proposal_counts.entry(destination).or_insert(0) += 1;
+        }
+    }
+
+    let mut has_moved = false;
+    let next_elves = elves
+        .iter()
+        .map(|&elf| match proposals.get(&elf) {
+            Some(destination) if proposal_counts[destination] == 1 => {
+                has_moved = true;
+                *destination
+            }
+            _ => elf,
+        })
+        .collect();
+
+    (next_elves, has_moved)
+}
+
+fn get_corners(elves: &HashSet<Point<i32>>) -> (Point<i32>, Point<i32>) {
+    let min_corner = Point::new(
+        elves.iter().map(|elf| elf.x).min().unwrap_or(0),
+        elves.iter().map(|elf| elf.y).min().unwrap_or(0),
+    );
+    let max_corner = Point::new(
+        elves.iter().map(|elf| elf.x).max().unwrap_or(-1),
+        elves.iter().map(|elf| elf.y).max().unwrap_or(-1),
+    );
+    (min_corner, max_corner)
+}
+
+// The tiles between the corners, telling where the elves are.
+fn get_elf_field(
+    elves: &HashSet<Point<i32>>,
+    (min_corner, max_corner): (Point<i32>, Point<i32>),
+) -> Grid<bool> {
+    let width = (max_corner.x - min_corner.x + 1) as usize;
+    let height = (max_corner.y - min_corner.y + 1) as usize;
+    let mut elf_field = Grid::new(height, width, false);
+    for elf in elves.iter() {
+        elf_field[Point::new(
+            (elf.x - min_corner.x) as usize,
+            (elf.y - min_corner.y) as usize,
+        )] = true;
+    }
+    elf_field
+}
+
+fn get_tile(&is_elf: &bool) -> char {
+    if is_elf {
+        '#'
+    } else {
+        '.'
+    }
+}
@@ -0,0 +1,30 @@
+use common::animation::record;
+use common::image::render;
+use day23::Day23;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn animation_shows_the_elves_spreading_out() {
+    let frames = record::<Day23>(EXAMPLE, 100).unwrap();
+
+    assert_eq!(frames[0], EXAMPLE.trim_end());
+    // After ten rounds, the smallest rectangle holding the elves has 110 empty
+    // ground tiles.
+    assert_eq!(frames[10].matches('.').count(), 110);
+    // The elves stop moving in round 20.
+    assert_eq!(frames.len(), 20);
+}
+
+#[test]
+fn render_draws_every_round_in_the_same_field() {
+    let images = render::<Day23>(EXAMPLE).unwrap();
+
+    assert_eq!(images.len(), 20);
+    let size = (images[0].width(), images[0].height());
+    assert!(images
+        .iter()
+        .all(|image| (image.width(), image.height()) == size));
+    // The elves end up spreading further than the initial 7 by 7 tiles.
+    assert!(size.0 > 7 * 4 && size.1 > 7 * 4);
+}
@@ -0,0 +1,14 @@
+use common::{solve, Part};
+use day23::Day23;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day23>(EXAMPLE, Part::One).unwrap(), "110");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day23>(EXAMPLE, Part::Two).unwrap(), "20");
+}
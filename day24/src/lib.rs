@@ -0,0 +1,405 @@
+use common::search::{bfs, Path};
+use common::{
+    Animation, Direction, Generator, Grid, Oracle, ParseError, Point, Random, Recording,
+    SolveError, Solver,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd)]
+enum Journey {
+    Go,
+    ComeBack,
+    GoBack,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct PositionWithJourney {
+    position: Point<usize>,
+    journey: Journey,
+}
+
+#[derive(Debug, Clone)]
+struct Blizzard {
+    position: Point<usize>,
+    direction: Direction,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Tile {
+    Wall,
+    Ground,
+    Blizzard(Direction),
+}
+
+pub struct InitialInput {
+    walls: Grid<bool>,
+    blizzards: Vec<Blizzard>,
+}
+
+type ExpeditionState = (PositionWithJourney, usize);
+
+const START_POSITION: Point<usize> = Point::new(1, 0);
+
+mod naive;
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day24;
+
+impl Solver for Day24 {
+    type Input = InitialInput;
+    type AnswerOne = usize;
+    type AnswerTwo = usize;
+
+    fn parse(input: &str) -> Result<InitialInput, ParseError> {
+        parse_initial_blizzard(input)
+    }
+
+    fn part_one(initial_input: &InitialInput) -> Result<usize, SolveError> {
+        count_steps(initial_input, Journey::ComeBack)
+    }
+
+    fn part_two(initial_input: &InitialInput) -> Result<usize, SolveError> {
+        count_steps(initial_input, Journey::GoBack)
+    }
+}
+
+// The size is the width of the valley inside its walls, a third of it being
+// its height. Half of the tiles hold a blizzard, none going up or down the
+// columns of the openings as in the real valleys, and the valley is drawn
+// again until the expedition can go there, back and there again.
+impl Generator for Day24 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        let width = size.max(1) + 2;
+        let height = (size / 3).max(1) + 2;
+        loop {
+            let mut valley = Grid::new(height, width, '#');
+            for position in valley.points().collect::<Vec<_>>() {
+                if position.x == 0
+                    || position.x == width - 1
+                    || position.y == 0
+                    || position.y == height - 1
+                {
+                    continue;
+                }
+                let is_opening_column = position.x == START_POSITION.x || position.x == width - 2;
+                valley[position] = match random.below(8) {
+                    0 => '>',
+                    1 => '<',
+                    2 if !is_opening_column => '^',
+                    3 if !is_opening_column => 'v',
+                    _ => '.',
+                };
+            }
+            valley[START_POSITION] = '.';
+            valley[Point::new(width - 2, height - 1)] = '.';
+
+            let input = valley.render(|&tile| tile) + "\n";
+            let initial_input = Day24::parse(&input).unwrap();
+            if find_trip(&initial_input, Journey::GoBack).is_some() {
+                return input;
+            }
+        }
+    }
+}
+
+// The first solution, moving every position the expedition can be at and
+// looking for the blizzards in a list.
+impl Oracle for Day24 {
+    // The naive solution takes seconds on valleys as large as the real one.
+    const MAX_COMPARED_SIZE: usize = 30;
+
+    fn naive_part_one(initial_input: &InitialInput) -> Result<usize, SolveError> {
+        naive::count_steps(initial_input, Journey::ComeBack).ok_or_else(get_blocked_error)
+    }
+
+    fn naive_part_two(initial_input: &InitialInput) -> Result<usize, SolveError> {
+        naive::count_steps(initial_input, Journey::GoBack).ok_or_else(get_blocked_error)
+    }
+}
+
+// Follows the expedition there, back and there again, one frame per minute.
+impl Animation for Day24 {
+    fn animate(initial_input: &InitialInput, recording: &mut Recording) {
+        let Some(trip) = find_trip(initial_input, Journey::GoBack) else {
+            return;
+        };
+        let line_number_max = initial_input.walls.height() - 1;
+        let column_number_max = initial_input.walls.width() - 1;
+
+        let mut blizzards = initial_input.blizzards.clone();
+        for (position_with_journey, _) in trip.nodes {
+            if recording.is_full() {
+                return;
+            }
+            recording.record(|| {
+                render_valley(
+                    &initial_input.walls,
+                    &blizzards,
+                    position_with_journey.position,
+                )
+            });
+            blizzards = get_next_blizzards(&blizzards, line_number_max, column_number_max);
+        }
+    }
+}
+
+fn count_steps(initial_input: &InitialInput, final_journey: Journey) -> Result<usize, SolveError> {
+    find_trip(initial_input, final_journey)
+        .map(|trip| trip.cost)
+        .ok_or_else(get_blocked_error)
+}
+
+fn get_blocked_error() -> SolveError {
+    SolveError::new("the blizzards block every way through the valley")
+}
+
+fn find_trip(
+    initial_input: &InitialInput,
+    final_journey: Journey,
+) -> Option<Path<ExpeditionState, usize>> {
+    let walls = &initial_input.walls;
+    let line_number_max = walls.height() - 1;
+    let column_number_max = walls.width() - 1;
+
+    let blizzard_positions_by_minute = get_blizzard_positions_by_minute(initial_input);
+    let period = blizzard_positions_by_minute.len();
+
+    let start = (
+        PositionWithJourney {
+            position: START_POSITION,
+            journey: Journey::Go,
+        },
+        0,
+    );
+
+    bfs(
+        start,
+        |&(position_with_journey, minute)| {
+            let next_minute = (minute + 1) % period;
+            let blizzard_positions = &blizzard_positions_by_minute[next_minute];
+            get_neighbors(position_with_journey, walls)
+                .into_iter()
+                .filter(|neighbor| !blizzard_positions[neighbor.position])
+                .map(move |neighbor| (neighbor, next_minute))
+        },
+        |(position_with_journey, _)| {
+            position_with_journey.journey == final_journey
+                && is_end_position(
+                    &position_with_journey.position,
+                    line_number_max,
+                    column_number_max,
+                )
+        },
+    )
+}
+
+// The blizzards are back to their initial positions once they all went around
+// the valley, so the valley only goes through that many different states.
+fn get_blizzard_positions_by_minute(initial_input: &InitialInput) -> Vec<Grid<bool>> {
+    let walls = &initial_input.walls;
+    let line_number_max = walls.height() - 1;
+    let column_number_max = walls.width() - 1;
+    let period = get_least_common_multiple(line_number_max - 1, column_number_max - 1);
+
+    let mut blizzards = initial_input.blizzards.clone();
+    (0..period)
+        .map(|_| {
+            let blizzard_positions = get_blizzard_positions(&blizzards, walls);
+            blizzards = get_next_blizzards(&blizzards, line_number_max, column_number_max);
+            blizzard_positions
+        })
+        .collect()
+}
+
+fn get_least_common_multiple(a: usize, b: usize) -> usize {
+    let (mut gcd, mut remainder) = (a, b);
+    while remainder != 0 {
+        (gcd, remainder) = (remainder, gcd % remainder);
+    }
+    a / gcd * b
+}
+
+fn parse_initial_blizzard(input: &str) -> Result<InitialInput, ParseError> {
+    let valley = Grid::parse(
+        input,
+        "`#`, `.`, `>`, `<`, `^` or `v`",
+        |char_value| match char_value {
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Ground),
+            '>' => Some(Tile::Blizzard(Direction::Right)),
+            '<' => Some(Tile::Blizzard(Direction::Left)),
+            '^' => Some(Tile::Blizzard(Direction::Up)),
+            'v' => Some(Tile::Blizzard(Direction::Down)),
+            _ => None,
+        },
+    )?;
+    let line_number_max = valley.height() - 1;
+    let column_number_max = valley.width() - 1;
+
+    if line_number_max < 2 || column_number_max < 2 {
+        return Err(ParseError::at(0, 0, "a valley of at least 3 by 3 tiles"));
+    }
+
+    let mut blizzards: Vec<Blizzard> = Vec::new();
+
+    for position in valley.points() {
+        let tile = valley[position];
+        let is_border = position.y == 0
+            || position.y == line_number_max
+            || position.x == 0
+            || position.x == column_number_max;
+        let is_opening = is_start_position(&position)
+            || is_end_position(&position, line_number_max, column_number_max);
+
+        let expected = match (is_border, is_opening) {
+            (_, true) if tile != Tile::Ground => Some("`.`"),
+            (true, false) if tile != Tile::Wall => Some("`#`"),
+            (false, _) if tile == Tile::Wall => Some("`.`, `>`, `<`, `^` or `v`"),
+            _ => None,
+        };
+        if let Some(expected) = expected {
+            return Err(ParseError::at(position.y, position.x, expected));
+        }
+
+        if let Tile::Blizzard(direction) = tile {
+            blizzards.push(Blizzard {
+                position,
+                direction,
+            });
+        }
+    }
+
+    Ok(InitialInput {
+        walls: valley.map(|&tile| tile == Tile::Wall),
+        blizzards,
+    })
+}
+
+fn get_next_blizzards(
+    blizzards: &[Blizzard],
+    line_number_max: usize,
+    column_number_max: usize,
+) -> Vec<Blizzard> {
+    blizzards
+        .iter()
+        .map(|blizzard| {
+            let mut position = blizzard.position.step(blizzard.direction);
+
+            // A blizzard reaching a wall reappears on the other side of the valley.
+            match blizzard.direction {
+                Direction::Up if position.y == 0 => position.y = line_number_max - 1,
+                Direction::Down if position.y == line_number_max => position.y = 1,
+                Direction::Left if position.x == 0 => position.x = column_number_max - 1,
+                Direction::Right if position.x == column_number_max => position.x = 1,
+                _ => {}
+            }
+
+            Blizzard {
+                position,
+                direction: blizzard.direction,
+            }
+        })
+        .collect()
+}
+
+fn get_blizzard_positions(blizzards: &[Blizzard], walls: &Grid<bool>) -> Grid<bool> {
+    let mut blizzard_positions = Grid::new(walls.height(), walls.width(), false);
+    for blizzard in blizzards {
+        blizzard_positions[blizzard.position] = true;
+    }
+    blizzard_positions
+}
+
+fn is_start_position(position: &Point<usize>) -> bool {
+    *position == START_POSITION
+}
+
+fn is_end_position(
+    position: &Point<usize>,
+    line_number_max: usize,
+    column_number_max: usize,
+) -> bool {
+    *position == Point::new(column_number_max - 1, line_number_max)
+}
+
+// Waiting in place is always possible, the walls are the only other limit.
+fn get_neighbors_position(position: Point<usize>, walls: &Grid<bool>) -> Vec<Point<usize>> {
+    std::iter::once(position)
+        .chain(walls.neighbours(position))
+        .filter(|&neighbour| !walls[neighbour])
+        .collect()
+}
+
+fn get_neighbors(
+    position_with_journey: PositionWithJourney,
+    walls: &Grid<bool>,
+) -> Vec<PositionWithJourney> {
+    let journey = position_with_journey.journey;
+    let line_number_max = walls.height() - 1;
+    let column_number_max = walls.width() - 1;
+
+    get_neighbors_position(position_with_journey.position, walls)
+        .into_iter()
+        .map(|position| {
+            if is_start_position(&position) && journey == Journey::ComeBack {
+                return PositionWithJourney {
+                    position,
+                    journey: Journey::GoBack,
+                };
+            }
+
+            if is_end_position(&position, line_number_max, column_number_max)
+                && journey == Journey::Go
+            {
+                return PositionWithJourney {
+                    position,
+                    journey: Journey::ComeBack,
+                };
+            }
+
+            PositionWithJourney { position, journey }
+        })
+        .collect()
+}
+
+// Draws the valley as the puzzle does, with the number of blizzards on tiles
+// holding several of them.
+fn render_valley(walls: &Grid<bool>, blizzards: &[Blizzard], expedition: Point<usize>) -> String {
+    let mut blizzards_by_tile: Grid<Vec<Direction>> =
+        Grid::new(walls.height(), walls.width(), Vec::new());
+    for blizzard in blizzards {
+        blizzards_by_tile[blizzard.position].push(blizzard.direction);
+    }
+
+    walls
+        .lines()
+        .enumerate()
+        .map(|(y, line)| {
+            line.iter()
+                .enumerate()
+                .map(|(x, &is_wall)| {
+                    let position = Point::new(x, y);
+                    if position == expedition {
+                        return 'E';
+                    }
+                    if is_wall {
+                        return '#';
+                    }
+                    match blizzards_by_tile[position][..] {
+                        [] => '.',
+                        [Direction::Right] => '>',
+                        [Direction::Left] => '<',
+                        [Direction::Up] => '^',
+                        [Direction::Down] => 'v',
+                        ref overlapping => {
+                            char::from_digit(overlapping.len() as u32, 10).unwrap_or('*')
+                        }
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
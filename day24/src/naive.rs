@@ -0,0 +1,61 @@
+use crate::{
+    get_least_common_multiple, get_neighbors, get_next_blizzards, is_end_position, Blizzard,
+    InitialInput, Journey, PositionWithJourney, START_POSITION,
+};
+use common::Point;
+use std::collections::HashSet;
+
+// Moves every position the expedition can be at, minute after minute, until one
+// of them is at the end of the final journey. The blizzards are back to their
+// initial positions every period: positions already seen at such a minute mean
+// the expedition goes round in circles, the blizzards blocking every way.
+pub fn count_steps(initial_input: &InitialInput, final_journey: Journey) -> Option<usize> {
+    let walls = &initial_input.walls;
+    let line_number_max = walls.height() - 1;
+    let column_number_max = walls.width() - 1;
+    let period = get_least_common_multiple(line_number_max - 1, column_number_max - 1);
+
+    let mut blizzards = initial_input.blizzards.clone();
+    let mut positions_with_journey = HashSet::from([PositionWithJourney {
+        position: START_POSITION,
+        journey: Journey::Go,
+    }]);
+    let mut positions_by_period: Vec<HashSet<PositionWithJourney>> = Vec::new();
+    let mut count = 0;
+
+    loop {
+        if count % period == 0 {
+            if positions_by_period.contains(&positions_with_journey) {
+                return None;
+            }
+            positions_by_period.push(positions_with_journey.clone());
+        }
+
+        blizzards = get_next_blizzards(&blizzards, line_number_max, column_number_max);
+        positions_with_journey = positions_with_journey
+            .iter()
+            .flat_map(|&position_with_journey| get_neighbors(position_with_journey, walls))
+            .filter(|position_with_journey| {
+                !is_position_taken(&blizzards, &position_with_journey.position)
+            })
+            .collect();
+        count += 1;
+
+        if positions_with_journey.iter().any(|position_with_journey| {
+            position_with_journey.journey == final_journey
+                && is_end_position(
+                    &position_with_journey.position,
+                    line_number_max,
+                    column_number_max,
+                )
+        }) {
+            return Some(count);
+        }
+    }
+}
+
+fn is_position_taken(blizzards: &[Blizzard], position: &Point<usize>) -> bool {
+    blizzards
+        .iter()
+        .any(|blizzard| blizzard.position == *position)
+}
@@ -0,0 +1,23 @@
+use common::animation::record;
+use day24::Day24;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn animation_follows_the_whole_trip() {
+    let frames = record::<Day24>(EXAMPLE, 1000).unwrap();
+
+    // The initial valley, then one frame for each of the 54 minutes.
+    assert_eq!(frames.len(), 55);
+    assert_eq!(
+        frames[0],
+        "\
+#E######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#"
+    );
+    assert!(frames[54].ends_with("######E#"));
+}
@@ -0,0 +1,14 @@
+use common::{solve, Part};
+use day24::Day24;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day24>(EXAMPLE, Part::One).unwrap(), "18");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(solve::<Day24>(EXAMPLE, Part::Two).unwrap(), "54");
+}
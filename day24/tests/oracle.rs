@@ -0,0 +1,7 @@
+use common::oracle::compare;
+use day24::Day24;
+
+#[test]
+fn optimised_solutions_agree_with_the_naive_ones() {
+    assert_eq!(compare::<Day24>(0, 200, Some(15)), Ok(()));
+}
@@ -0,0 +1,113 @@
+use common::{Generator, ParseError, Random, SolveError, Solver};
+
+pub const DEFAULT_INPUT: &str = common::default_input!();
+
+pub struct Day25;
+
+impl Solver for Day25 {
+    type Input = Vec<i64>;
+    type AnswerOne = String;
+    type AnswerTwo = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(line_index, line)| {
+                match line
+                    .chars()
+                    .position(|snafu_part| snafu_part_to_digit(snafu_part).is_none())
+                {
+                    Some(column_index) => Err(ParseError::at(
+                        line_index,
+                        column_index,
+                        "`2`, `1`, `0`, `-` or `=`",
+                    )),
+                    None => snafu_to_decimal(line).ok_or_else(|| {
+                        ParseError::at(line_index, 0, "a SNAFU number fitting in 64 bits")
+                    }),
+                }
+            })
+            .collect()
+    }
+
+    fn part_one(decimals: &Vec<i64>) -> Result<String, SolveError> {
+        let accumulator = decimals
+            .iter()
+            .try_fold(0_i64, |accumulator, &decimal| {
+                accumulator.checked_add(decimal)
+            })
+            .ok_or_else(|| SolveError::new("the sum of the fuel requirements overflows"))?;
+
+        Ok(decimal_to_snafu(accumulator))
+    }
+
+    // The last day of the calendar has a single puzzle.
+    fn part_two(_decimals: &Vec<i64>) -> Result<&'static str, SolveError> {
+        Ok("Merry Christmas!")
+    }
+}
+
+// The size is the number of fuel requirements.
+impl Generator for Day25 {
+    const DEFAULT_SIZE: usize = 120;
+
+    fn generate(random: &mut Random, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let digit_count = random.between(1, 18) as u32;
+                decimal_to_snafu(random.between(1, 5_i64.pow(digit_count))) + "\n"
+            })
+            .collect()
+    }
+}
+
+fn snafu_part_to_digit(snafu_part: char) -> Option<i64> {
+    match snafu_part {
+        '0' => Some(0),
+        '1' => Some(1),
+        '2' => Some(2),
+        '-' => Some(-1),
+        '=' => Some(-2),
+        _ => None,
+    }
+}
+
+// Nothing when the number does not fit in 64 bits.
+fn snafu_to_decimal(snafu: &str) -> Option<i64> {
+    snafu.chars().try_fold(0_i64, |decimal, snafu_part| {
+        decimal
+            .checked_mul(5)?
+            .checked_add(snafu_part_to_digit(snafu_part)?)
+    })
+}
+
+fn digit_to_snafu_part(digit: i64) -> char {
+    match digit {
+        -2 => '=',
+        -1 => '-',
+        0 => '0',
+        1 => '1',
+        2 => '2',
+        _ => unreachable!("SNAFU digits are between -2 and 2"),
+    }
+}
+
+// Negative numbers are written the same way, their leading digit being `-` or
+// `=`. The remainder is taken from below so that the digits never overflow.
+fn decimal_to_snafu(decimal: i64) -> String {
+    let mut remain = decimal;
+    let mut stafu: Vec<char> = Vec::new();
+
+    loop {
+        let digit = (remain.rem_euclid(5) + 2) % 5 - 2;
+        stafu.push(digit_to_snafu_part(digit));
+        remain = remain.div_euclid(5) + i64::from(digit < 0);
+        if remain == 0 {
+            break;
+        }
+    }
+
+    stafu.reverse();
+    stafu.into_iter().collect()
+}
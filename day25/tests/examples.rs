@@ -0,0 +1,48 @@
+use common::{solve, Error, ParseError, Part, SolveError};
+use day25::Day25;
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+fn part_one_example() {
+    assert_eq!(solve::<Day25>(EXAMPLE, Part::One).unwrap(), "2=-1=0");
+}
+
+#[test]
+fn part_two_example() {
+    assert_eq!(
+        solve::<Day25>(EXAMPLE, Part::Two).unwrap(),
+        "Merry Christmas!"
+    );
+}
+
+#[test]
+fn negative_sum() {
+    assert_eq!(solve::<Day25>("=2\n", Part::One).unwrap(), "=2");
+    assert_eq!(solve::<Day25>("1=\n--\n", Part::One).unwrap(), "-2");
+}
+
+#[test]
+fn number_too_large() {
+    let input = "1=\n2222222222222222222222222222\n";
+    assert_eq!(
+        solve::<Day25>(input, Part::One),
+        Err(Error::Parse(ParseError::at(
+            1,
+            0,
+            "a SNAFU number fitting in 64 bits"
+        )))
+    );
+}
+
+#[test]
+fn sum_too_large() {
+    // 5^27 is just below the largest 64-bit number, and twice as much above it.
+    let input = "1000000000000000000000000000\n1000000000000000000000000000\n";
+    assert_eq!(
+        solve::<Day25>(input, Part::One),
+        Err(Error::Solve(SolveError::new(
+            "the sum of the fuel requirements overflows"
+        )))
+    );
+}